@@ -0,0 +1,120 @@
+// axion-db/src/auto_refresh.rs
+use crate::{
+    config::DatabaseType,
+    error::{DbError, DbResult},
+    manager::ModelManager,
+};
+use sqlx::postgres::PgListener;
+use std::time::Duration;
+use tokio::task::JoinHandle;
+use tracing::{info, warn};
+
+/// How long to keep collecting notifications before refreshing, so a migration that
+/// issues many DDL statements triggers a single re-introspection.
+const DEBOUNCE: Duration = Duration::from_millis(500);
+
+/// How long to wait before listening again after the connection is lost.
+const RECONNECT_DELAY: Duration = Duration::from_secs(5);
+
+/// A running auto-refresh task. Dropping the handle (or calling `cancel`) stops it.
+#[must_use = "the auto-refresh task stops as soon as its handle is dropped"]
+#[derive(Debug)]
+pub struct AutoRefreshHandle {
+    channel: String,
+    task: JoinHandle<()>,
+}
+
+impl AutoRefreshHandle {
+    /// The channel the task is listening on.
+    pub fn channel(&self) -> &str {
+        &self.channel
+    }
+
+    /// Stops the task. A refresh already in progress is abandoned.
+    pub fn cancel(self) {}
+}
+
+impl Drop for AutoRefreshHandle {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+impl ModelManager {
+    /// Starts a background task that `LISTEN`s on `channel` and calls `refresh` whenever
+    /// a notification arrives, and once more after reconnecting, since notifications sent
+    /// while the connection was down are lost. Pair it with an event trigger that
+    /// notifies on DDL:
+    ///
+    /// ```sql
+    /// CREATE FUNCTION notify_schema_changed() RETURNS event_trigger AS $$
+    /// BEGIN PERFORM pg_notify('axion_schema_changed', tg_tag); END;
+    /// $$ LANGUAGE plpgsql;
+    /// CREATE EVENT TRIGGER axion_schema_changed ON ddl_command_end
+    ///     EXECUTE FUNCTION notify_schema_changed();
+    /// ```
+    ///
+    /// The listener uses its own connection, outside the pool. Only Postgres is supported.
+    pub async fn spawn_auto_refresh(&self, channel: &str) -> DbResult<AutoRefreshHandle> {
+        let config = &self.db_client.config;
+        if config.db_type != DatabaseType::Postgres {
+            return Err(DbError::UnsupportedDbType(format!(
+                "LISTEN/NOTIFY auto-refresh requires Postgres, not {:?}",
+                config.db_type
+            )));
+        }
+
+        let mut listener = PgListener::connect(&config.build_connection_string()?).await?;
+        listener.listen(channel).await?;
+        info!("Listening for schema changes on channel '{}'", channel);
+
+        let manager = self.clone();
+        let channel_name = channel.to_string();
+        let task = tokio::spawn(async move {
+            loop {
+                match listener.try_recv().await {
+                    Ok(Some(_)) => {
+                        // Swallow the rest of the burst before refreshing.
+                        while let Ok(Ok(Some(_))) =
+                            tokio::time::timeout(DEBOUNCE, listener.try_recv()).await
+                        {
+                        }
+                        info!("Schema change notified on '{}'", channel_name);
+                    }
+                    // The connection dropped and the listener has already reconnected.
+                    // Notifications sent in between are lost, so refresh regardless.
+                    Ok(None) => {
+                        info!("Schema change listener on '{}' reconnected", channel_name);
+                    }
+                    Err(e) => {
+                        warn!(
+                            "Schema change listener on '{}' lost its connection: {}",
+                            channel_name, e
+                        );
+                        // Reconnect (and LISTEN again) now instead of on the next
+                        // notification, so the refresh below catches up on missed changes.
+                        loop {
+                            tokio::time::sleep(RECONNECT_DELAY).await;
+                            match sqlx::query("SELECT 1").execute(&mut listener).await {
+                                Ok(_) => break,
+                                Err(e) => warn!(
+                                    "Schema change listener on '{}' could not reconnect: {}",
+                                    channel_name, e
+                                ),
+                            }
+                        }
+                        info!("Schema change listener on '{}' reconnected", channel_name);
+                    }
+                }
+                if let Err(e) = manager.refresh().await {
+                    warn!("Auto-refresh after schema change failed: {}", e);
+                }
+            }
+        });
+
+        Ok(AutoRefreshHandle {
+            channel: channel.to_string(),
+            task,
+        })
+    }
+}
@@ -0,0 +1,257 @@
+// axion-db/src/builder.rs
+use crate::metadata::{
+    AxionDataType, ColumnMetadata, DatabaseMetadata, EnumMetadata, ForeignKeyReference,
+    IndexMetadata, ReferentialAction, SchemaMetadata, TableMetadata, ViewMetadata,
+};
+
+/// Assembles a `DatabaseMetadata` by hand, mainly for tests that need metadata
+/// without a live database.
+///
+/// Tables, views and enums are added to the schema most recently selected with `schema`.
+///
+/// ```
+/// use axion_db::prelude::*;
+///
+/// let metadata = MetadataBuilder::new()
+///     .schema("public")
+///     .table("users", |t| {
+///         t.column("id", AxionDataType::Uuid)
+///             .column("email", AxionDataType::Text)
+///             .pk("id")
+///     })
+///     .build();
+/// assert_eq!(metadata.stats().tables, 1);
+/// ```
+#[derive(Debug, Default)]
+pub struct MetadataBuilder {
+    metadata: DatabaseMetadata,
+    current_schema: Option<String>,
+}
+
+impl MetadataBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Selects the schema that following entities are added to, creating it if needed.
+    pub fn schema(mut self, name: impl Into<String>) -> Self {
+        let name = name.into();
+        self.metadata
+            .schemas
+            .entry(name.clone())
+            .or_insert_with(|| SchemaMetadata {
+                name: name.clone(),
+                ..Default::default()
+            });
+        self.current_schema = Some(name);
+        self
+    }
+
+    /// Adds a table to the current schema, configured through `build`.
+    pub fn table(
+        mut self,
+        name: impl Into<String>,
+        build: impl FnOnce(TableBuilder) -> TableBuilder,
+    ) -> Self {
+        let schema = self.current_schema_mut();
+        let table = build(TableBuilder::new(&schema.name, name.into())).table;
+        schema.tables.insert(table.name.clone(), table);
+        self
+    }
+
+    /// Adds a view to the current schema. Its columns are declared the same way as a table's.
+    pub fn view(
+        mut self,
+        name: impl Into<String>,
+        build: impl FnOnce(TableBuilder) -> TableBuilder,
+    ) -> Self {
+        let schema = self.current_schema_mut();
+        let table = build(TableBuilder::new(&schema.name, name.into())).table;
+        let view = ViewMetadata {
+            name: table.name,
+            schema: table.schema,
+            columns: table.columns,
+            comment: table.comment,
+            ..Default::default()
+        };
+        schema.views.insert(view.name.clone(), view);
+        self
+    }
+
+    /// Adds an enum with the given values, in declaration order, to the current schema.
+    pub fn enum_type(mut self, name: impl Into<String>, values: &[&str]) -> Self {
+        let schema = self.current_schema_mut();
+        let name = name.into();
+        let enum_md = EnumMetadata {
+            name: name.clone(),
+            schema: schema.name.clone(),
+            values: values.iter().map(|v| v.to_string()).collect(),
+            comment: None,
+            is_declaration_order: true,
+        };
+        schema.enums.insert(name, enum_md);
+        self
+    }
+
+    pub fn build(self) -> DatabaseMetadata {
+        self.metadata
+    }
+
+    /// The schema selected by the last call to `schema`, defaulting to `public`.
+    fn current_schema_mut(&mut self) -> &mut SchemaMetadata {
+        let name = self
+            .current_schema
+            .get_or_insert_with(|| "public".to_string())
+            .clone();
+        self.metadata
+            .schemas
+            .entry(name.clone())
+            .or_insert_with(|| SchemaMetadata {
+                name,
+                ..Default::default()
+            })
+    }
+}
+
+/// Declares the columns and keys of a table (or view) for `MetadataBuilder`.
+/// Columns are nullable unless they are part of the primary key or marked `not_null`.
+#[derive(Debug)]
+pub struct TableBuilder {
+    table: TableMetadata,
+}
+
+impl TableBuilder {
+    fn new(schema: &str, name: String) -> Self {
+        Self {
+            table: TableMetadata {
+                name,
+                schema: schema.to_string(),
+                ..Default::default()
+            },
+        }
+    }
+
+    /// Adds a nullable column. The SQL type name is derived from the Axion type.
+    pub fn column(mut self, name: impl Into<String>, axion_type: AxionDataType) -> Self {
+        self.table.columns.push(ColumnMetadata {
+            name: name.into(),
+            sql_type_name: axion_type.to_string().to_lowercase(),
+            canonical_sql_type: axion_type.to_string().to_lowercase(),
+            axion_type,
+            is_nullable: true,
+            is_primary_key: false,
+            is_auto_increment: false,
+            default_value: None,
+            collation: None,
+            comment: None,
+            foreign_key: None,
+            is_system: false,
+        });
+        self
+    }
+
+    /// Appends `column` to the primary key and marks it `NOT NULL`.
+    /// Call once per column for composite keys, in key order.
+    pub fn pk(mut self, column: &str) -> Self {
+        if let Some(col) = self.column_mut(column) {
+            col.is_primary_key = true;
+            col.is_nullable = false;
+        }
+        self.table.primary_key_columns.push(column.to_string());
+        self
+    }
+
+    /// Marks `column` as `NOT NULL`.
+    pub fn not_null(mut self, column: &str) -> Self {
+        if let Some(col) = self.column_mut(column) {
+            col.is_nullable = false;
+        }
+        self
+    }
+
+    /// Makes `column` reference `schema.table.references`.
+    pub fn foreign_key(
+        mut self,
+        column: &str,
+        schema: &str,
+        table: &str,
+        references: &str,
+    ) -> Self {
+        if let Some(col) = self.column_mut(column) {
+            col.foreign_key = Some(ForeignKeyReference {
+                schema: schema.to_string(),
+                table: table.to_string(),
+                column: references.to_string(),
+                on_delete: ReferentialAction::default(),
+                on_update: ReferentialAction::default(),
+            });
+        }
+        self
+    }
+
+    /// Adds a unique index over `columns`, named after the table and columns.
+    pub fn unique(mut self, columns: &[&str]) -> Self {
+        self.table.indexes.push(IndexMetadata {
+            name: format!("{}_{}_key", self.table.name, columns.join("_")),
+            columns: columns.iter().map(|c| c.to_string()).collect(),
+            is_unique: true,
+            is_primary: false,
+        });
+        self
+    }
+
+    pub fn comment(mut self, comment: impl Into<String>) -> Self {
+        self.table.comment = Some(comment.into());
+        self
+    }
+
+    fn column_mut(&mut self, name: &str) -> Option<&mut ColumnMetadata> {
+        self.table.columns.iter_mut().find(|col| col.name == name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn builds_schemas_tables_views_and_enums() {
+        let metadata = MetadataBuilder::new()
+            .schema("auth")
+            .table("users", |t| {
+                t.column("id", AxionDataType::Uuid)
+                    .column("email", AxionDataType::Text)
+                    .pk("id")
+                    .not_null("email")
+            })
+            .schema("public")
+            .table("posts", |t| {
+                t.column("id", AxionDataType::Integer(64))
+                    .column("author_id", AxionDataType::Uuid)
+                    .pk("id")
+                    .foreign_key("author_id", "auth", "users", "id")
+            })
+            .view("recent_posts", |v| {
+                v.column("id", AxionDataType::Integer(64))
+            })
+            .enum_type("mood", &["happy", "sad"])
+            .build();
+
+        let stats = metadata.stats();
+        assert_eq!(
+            (stats.schemas, stats.tables, stats.views, stats.enums),
+            (2, 2, 1, 1)
+        );
+
+        let users = &metadata.schemas["auth"].tables["users"];
+        assert_eq!(users.primary_key_columns, ["id"]);
+        assert!(users.columns.iter().all(|col| !col.is_nullable));
+
+        let relationships = metadata.relationships();
+        assert_eq!(relationships.len(), 1);
+        assert_eq!(
+            relationships[0].to_string(),
+            "public.posts.author_id -> auth.users.id"
+        );
+    }
+}
@@ -1,48 +1,147 @@
 // axion-db/src/client.rs
-use crate::config::DbConfig;
-use crate::error::DbResult;
+use crate::config::{DatabaseType, DbConfig};
+use crate::error::{DEFAULT_ACQUIRE_TIMEOUT, DbError, DbResult};
+use serde::{Deserialize, Serialize};
 use sqlx::any::AnyPoolOptions;
-use sqlx::{AnyPool, Connection};
+use sqlx::{AnyPool, Connection, Executor};
 use std::sync::Arc;
-use tracing::{debug, info};
+use std::time::Duration;
+use tracing::{debug, info, warn};
+
+/// A point-in-time reading of the primary pool's connection counters.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PoolStats {
+    /// Connections currently open, idle or in use.
+    pub size: u32,
+    pub idle: u32,
+    pub in_use: u32,
+}
 
 #[derive(Clone, Debug)]
 pub struct DbClient {
+    /// The primary pool, used for all queries that may write.
     pub pool: Arc<AnyPool>,
+    /// The pool used for read-only work such as introspection.
+    /// Points at the same pool as `pool` unless a replica is configured.
+    pub read_pool: Arc<AnyPool>,
     pub config: Arc<DbConfig>,
 }
 
 impl DbClient {
     pub async fn new(config: DbConfig) -> DbResult<Self> {
         info!("Initializing DbClient with config: {:?}", config.db_type);
-        let cs = config.build_connection_string()?;
-        debug!("Constructed connection string: [REDACTED]");
+        config.validate()?;
+        config.db_type.ensure_enabled()?;
+        let connect_options = config.to_sqlx_any_options()?;
+        debug!("Constructed connection options: [REDACTED]");
 
         let pool_options = if let Some(pool_config) = &config.pool_options {
-            AnyPoolOptions::new()
+            let mut options = AnyPoolOptions::new()
                 .max_connections(pool_config.max_connections.unwrap_or(5))
                 .min_connections(pool_config.min_connections.unwrap_or(1))
-            // Other options...
+                .acquire_timeout(
+                    pool_config
+                        .acquire_timeout_seconds
+                        .map_or(DEFAULT_ACQUIRE_TIMEOUT, Duration::from_secs),
+                );
+            // Unset values keep sqlx's defaults rather than disabling the setting.
+            if let Some(seconds) = pool_config.idle_timeout_seconds {
+                options = options.idle_timeout(Duration::from_secs(seconds));
+            }
+            if let Some(seconds) = pool_config.max_lifetime_seconds {
+                options = options.max_lifetime(Duration::from_secs(seconds));
+            }
+            if let Some(test) = pool_config.test_before_acquire {
+                options = options.test_before_acquire(test);
+            }
+            options
         } else {
             AnyPoolOptions::new()
         };
 
+        let pool_options = match (&config.db_type, config.statement_timeout_seconds) {
+            (DatabaseType::Postgres, Some(seconds)) => {
+                debug!(
+                    "Applying statement_timeout of {}s to new connections",
+                    seconds
+                );
+                let statement = format!("SET statement_timeout = {}", seconds * 1000);
+                pool_options.after_connect(move |conn, _meta| {
+                    let statement = statement.clone();
+                    Box::pin(async move {
+                        conn.execute(statement.as_str()).await?;
+                        Ok(())
+                    })
+                })
+            }
+            (db_type, Some(_)) => {
+                warn!(
+                    "statement_timeout_seconds is not supported for {}, ignoring",
+                    db_type
+                );
+                pool_options
+            }
+            (_, None) => pool_options,
+        };
+
+        if config.tcp_options.is_some() && config.db_type != DatabaseType::Postgres {
+            warn!(
+                "tcp_options is not supported for {}, ignoring",
+                config.db_type
+            );
+        }
+
         debug!("Connecting to database with type: {:?}", config.db_type);
-        let pool = pool_options.connect(&cs).await?;
+        let pool = Arc::new(
+            pool_options
+                .clone()
+                .connect_with(connect_options)
+                .await
+                .map_err(DbError::connect)?,
+        );
         info!(
             "Successfully connected to database: {:?}",
             config.database_name.as_deref().unwrap_or("default")
         );
 
+        let read_pool = match config.to_sqlx_replica_options()? {
+            Some(replica_options) => {
+                debug!("Connecting to read replica: [REDACTED]");
+                let read_pool = pool_options
+                    .connect_with(replica_options)
+                    .await
+                    .map_err(DbError::connect)?;
+                info!("Successfully connected to read replica.");
+                Arc::new(read_pool)
+            }
+            None => pool.clone(),
+        };
+
         Ok(Self {
-            pool: Arc::new(pool),
+            pool,
+            read_pool,
             config: Arc::new(config),
         })
     }
 
+    /// Reads the primary pool's current counters. Cheap enough to call on every health check.
+    pub fn pool_stats(&self) -> PoolStats {
+        let size = self.pool.size();
+        let idle = u32::try_from(self.pool.num_idle()).unwrap_or(u32::MAX);
+        PoolStats {
+            size,
+            idle,
+            in_use: size.saturating_sub(idle),
+        }
+    }
+
     pub async fn test_connection(&self) -> DbResult<()> {
         info!("Pinging database...");
-        let mut conn = self.pool.acquire().await?;
+        let mut conn = self
+            .pool
+            .acquire()
+            .await
+            .map_err(|e| DbError::from_pool(e, &self.pool))?;
         conn.ping().await?;
         info!("Database ping successful.");
         Ok(())
@@ -54,7 +153,10 @@ impl DbClient {
             // Simplified for brevity
             _ => "SELECT version()",
         };
-        let (version,): (String,) = sqlx::query_as(query).fetch_one(&*self.pool).await?;
+        let (version,): (String,) = sqlx::query_as(query)
+            .fetch_one(&*self.pool)
+            .await
+            .map_err(|e| DbError::from_pool(e, &self.pool))?;
         debug!("Database version: {}", version);
         Ok(version)
     }
@@ -0,0 +1,178 @@
+// axion-db/src/codegen.rs
+
+//! Naming rules shared by the code generators, so every generated type gets a valid,
+//! unique identifier no matter how the database objects are named.
+
+use crate::{
+    error::{DbError, DbResult},
+    metadata::{DatabaseMetadata, QualifiedName, to_pascal_case},
+};
+use std::collections::{BTreeMap, HashSet};
+use tracing::warn;
+
+/// What to do when two database objects map to the same generated identifier,
+/// e.g. `public.user_data` and `auth.user_data`, or `user-data` and `user_data`.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum CollisionStrategy {
+    /// Append a numeric suffix (`UserData2`, `UserData3`, ...) and log a warning.
+    #[default]
+    Suffix,
+    /// Fail with `DbError::Ambiguous`.
+    Error,
+}
+
+/// Options the struct and interface generators consult when naming generated types.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CodegenOptions {
+    /// Longest identifier to generate, in characters. Longer names are truncated
+    /// (with a warning), leaving room for any collision suffix.
+    pub max_ident_len: usize,
+    pub on_collision: CollisionStrategy,
+}
+
+impl Default for CodegenOptions {
+    fn default() -> Self {
+        Self {
+            max_ident_len: 64,
+            on_collision: CollisionStrategy::default(),
+        }
+    }
+}
+
+impl CodegenOptions {
+    /// Assigns a PascalCase type name to every table, view, enum and composite type.
+    /// Objects are named in order of their qualified name, so the same metadata always
+    /// yields the same names, and the first object keeps the unsuffixed one.
+    pub fn type_names(
+        &self,
+        metadata: &DatabaseMetadata,
+    ) -> DbResult<BTreeMap<QualifiedName, String>> {
+        let mut objects: Vec<QualifiedName> = metadata
+            .schemas
+            .values()
+            .flat_map(|schema| {
+                let names = schema
+                    .tables
+                    .keys()
+                    .chain(schema.views.keys())
+                    .chain(schema.enums.keys())
+                    .chain(schema.composite_types.keys());
+                names.map(|name| QualifiedName::new(&schema.name, name))
+            })
+            .collect();
+        objects.sort();
+
+        let mut taken = HashSet::new();
+        objects
+            .into_iter()
+            .map(|object| {
+                let ident =
+                    self.unique_ident(&object, &to_pascal_case(&object.name), &mut taken)?;
+                Ok((object, ident))
+            })
+            .collect()
+    }
+
+    /// Fits `ident` (generated for `source`) within `max_ident_len` and makes it distinct
+    /// from every identifier in `taken`, which it is then added to.
+    pub fn unique_ident(
+        &self,
+        source: &QualifiedName,
+        ident: &str,
+        taken: &mut HashSet<String>,
+    ) -> DbResult<String> {
+        let ident = sanitize(ident);
+        let base = truncate(&ident, self.max_ident_len);
+        if base.len() < ident.len() {
+            warn!(
+                "Identifier for {} truncated to '{}' (max {} characters)",
+                source, base, self.max_ident_len
+            );
+        }
+        if taken.insert(base.to_string()) {
+            return Ok(base.to_string());
+        }
+
+        if self.on_collision == CollisionStrategy::Error {
+            return Err(DbError::Ambiguous(format!(
+                "{} maps to the identifier '{}', which is already taken",
+                source, base
+            )));
+        }
+        let resolved = (2..)
+            .map(|n| {
+                let suffix = n.to_string();
+                let stem = truncate(&ident, self.max_ident_len.saturating_sub(suffix.len()));
+                format!("{}{}", stem, suffix)
+            })
+            .find(|candidate| !taken.contains(candidate))
+            .expect("some numeric suffix is always free");
+        warn!(
+            "Identifier '{}' for {} is already taken, using '{}'",
+            base, source, resolved
+        );
+        taken.insert(resolved.clone());
+        Ok(resolved)
+    }
+}
+
+/// Makes a PascalCase name a valid Rust and TypeScript identifier: names that are empty
+/// or start with a digit get a leading underscore, and `Self` gets a trailing one.
+pub(crate) fn sanitize(ident: &str) -> String {
+    match ident.chars().next() {
+        None => "_".to_string(),
+        Some(first) if first.is_ascii_digit() => format!("_{}", ident),
+        Some(_) if ident == "Self" => "Self_".to_string(),
+        Some(_) => ident.to_string(),
+    }
+}
+
+/// The first `max` characters of `ident`.
+fn truncate(ident: &str, max: usize) -> &str {
+    match ident.char_indices().nth(max) {
+        Some((end, _)) => &ident[..end],
+        None => ident,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{builder::MetadataBuilder, metadata::AxionDataType};
+
+    #[test]
+    fn type_names_resolve_collisions_and_truncate() {
+        let metadata = MetadataBuilder::new()
+            .schema("auth")
+            .table("user_data", |t| t.column("id", AxionDataType::Uuid))
+            .schema("public")
+            .table("user-data", |t| t.column("id", AxionDataType::Uuid))
+            .table("2fa_codes", |t| t.column("id", AxionDataType::Uuid))
+            .table("very_long_table_name", |t| {
+                t.column("id", AxionDataType::Uuid)
+            })
+            .enum_type("user_data", &["a", "b"])
+            .build();
+
+        let options = CodegenOptions {
+            max_ident_len: 9,
+            ..Default::default()
+        };
+        let names = options.type_names(&metadata).unwrap();
+        let name = |schema: &str, object: &str| names[&QualifiedName::new(schema, object)].as_str();
+        assert_eq!(name("auth", "user_data"), "UserData");
+        assert_eq!(name("public", "user-data"), "UserData2");
+        assert_eq!(name("public", "user_data"), "UserData3");
+        assert_eq!(name("public", "2fa_codes"), "_2faCodes");
+        assert_eq!(name("public", "very_long_table_name"), "VeryLongT");
+
+        let strict = CodegenOptions {
+            on_collision: CollisionStrategy::Error,
+            ..Default::default()
+        };
+        assert!(matches!(
+            strict.type_names(&metadata),
+            Err(DbError::Ambiguous(_))
+        ));
+    }
+}
@@ -1,17 +1,41 @@
 // axion-db/src/error.rs
+use sqlx::AnyPool;
+use std::time::Duration;
 use thiserror::Error;
 
+/// sqlx's default acquire timeout, reported when the actual pool is not at hand.
+pub(crate) const DEFAULT_ACQUIRE_TIMEOUT: Duration = Duration::from_secs(30);
+
 #[derive(Error, Debug)]
 pub enum DbError {
     #[error("Configuration error: {0}")]
     Config(String),
 
     #[error("SQLx connection error: {0}")]
-    Connection(#[from] sqlx::Error),
+    Connection(sqlx::Error),
+
+    #[error(
+        "Prepared statement conflict: {0}. This usually means the connection goes through a \
+         transaction-mode pooler such as PgBouncer; disable statement caching with \
+         `DbConfig::statement_cache_capacity(0)`"
+    )]
+    PreparedStatementConflict(sqlx::Error),
+
+    #[error(
+        "Connection pool exhausted: no connection became available within {timeout:?}. \
+         Increase `max_connections` or `acquire_timeout_seconds` in the pool options"
+    )]
+    PoolExhausted { timeout: Duration },
 
     #[error("Introspection error: {0}")]
     Introspection(String),
 
+    #[error("The catalog changed during introspection: {0}")]
+    CatalogChanged(String),
+
+    #[error("Introspection of '{schema}.{entity}' timed out")]
+    IntrospectionTimeout { schema: String, entity: String },
+
     #[error("Query execution error: {0}")]
     QueryExecution(sqlx::Error), // Keep original sqlx::Error for details
 
@@ -23,6 +47,299 @@ pub enum DbError {
 
     #[error("Feature not enabled for database: {0}")]
     FeatureNotEnabled(String),
+
+    #[error("Not found: {0}")]
+    NotFound(String),
+
+    #[error("Ambiguous name: {0}")]
+    Ambiguous(String),
+
+    #[error("Invalid input: {0}")]
+    InvalidInput(String),
+
+    #[error("I/O error: {0}")]
+    Io(#[from] std::io::Error),
+}
+
+impl DbError {
+    /// Converts an error raised while using `pool`, reporting the pool's own
+    /// acquire timeout if it was exhausted.
+    pub(crate) fn from_pool(err: sqlx::Error, pool: &AnyPool) -> Self {
+        DbError::classify(
+            err,
+            pool.options().get_acquire_timeout(),
+            DbError::Connection,
+        )
+    }
+
+    /// Like `from_pool`, but wraps any other error as `QueryExecution`.
+    pub(crate) fn query_execution(err: sqlx::Error, pool: &AnyPool) -> Self {
+        DbError::classify(
+            err,
+            pool.options().get_acquire_timeout(),
+            DbError::QueryExecution,
+        )
+    }
+
+    /// Converts an error raised while opening a pool. Timing out there means the server
+    /// could not be reached, not that the pool ran out of connections.
+    pub(crate) fn connect(err: sqlx::Error) -> Self {
+        match err {
+            sqlx::Error::PoolTimedOut => DbError::Connection(err),
+            err => err.into(),
+        }
+    }
+
+    /// The one place errors are sorted by cause: a pool timeout is `PoolExhausted` (after
+    /// `acquire_timeout`), a duplicate prepared statement is `PreparedStatementConflict`,
+    /// and anything else is wrapped with `other`.
+    fn classify(
+        err: sqlx::Error,
+        acquire_timeout: Duration,
+        other: fn(sqlx::Error) -> DbError,
+    ) -> Self {
+        if let sqlx::Error::PoolTimedOut = err {
+            return DbError::PoolExhausted {
+                timeout: acquire_timeout,
+            };
+        }
+        // Postgres reports this (SQLSTATE 42P05) when a pooler hands us a server
+        // connection that already has a statement with the same name prepared.
+        let message = err.to_string();
+        let is_duplicate_statement = sqlstate(&err).as_deref() == Some("42P05")
+            || (message.contains("prepared statement") && message.contains("already exists"));
+        if is_duplicate_statement {
+            DbError::PreparedStatementConflict(err)
+        } else {
+            other(err)
+        }
+    }
+
+    /// The SQLSTATE code the database reported, if the error came from it.
+    pub fn sqlstate(&self) -> Option<String> {
+        match self {
+            DbError::QueryExecution(err)
+            | DbError::Connection(err)
+            | DbError::PreparedStatementConflict(err) => sqlstate(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<sqlx::Error> for DbError {
+    fn from(err: sqlx::Error) -> Self {
+        DbError::classify(err, DEFAULT_ACQUIRE_TIMEOUT, DbError::Connection)
+    }
+}
+
+fn sqlstate(err: &sqlx::Error) -> Option<String> {
+    err.as_database_error()
+        .and_then(|db_err| db_err.code())
+        .map(|code| code.into_owned())
 }
 
 pub type DbResult<T> = Result<T, DbError>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sqlx::error::{DatabaseError, ErrorKind};
+    use std::{borrow::Cow, error::Error as StdError, fmt};
+
+    /// A database error carrying just a SQLSTATE code.
+    #[derive(Debug)]
+    struct FakeDbError(&'static str);
+
+    impl fmt::Display for FakeDbError {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "error with SQLSTATE {}", self.0)
+        }
+    }
+
+    impl StdError for FakeDbError {}
+
+    impl DatabaseError for FakeDbError {
+        fn message(&self) -> &str {
+            "fake"
+        }
+        fn code(&self) -> Option<Cow<'_, str>> {
+            Some(Cow::Borrowed(self.0))
+        }
+        fn as_error(&self) -> &(dyn StdError + Send + Sync + 'static) {
+            self
+        }
+        fn as_error_mut(&mut self) -> &mut (dyn StdError + Send + Sync + 'static) {
+            self
+        }
+        fn into_error(self: Box<Self>) -> Box<dyn StdError + Send + Sync + 'static> {
+            self
+        }
+        fn kind(&self) -> ErrorKind {
+            ErrorKind::Other
+        }
+    }
+
+    pub(super) fn database_error(code: &'static str) -> sqlx::Error {
+        sqlx::Error::Database(Box::new(FakeDbError(code)))
+    }
+
+    #[test]
+    fn errors_are_classified_the_same_on_every_path() {
+        let timeout = Duration::from_secs(5);
+        let as_query = |err| DbError::classify(err, timeout, DbError::QueryExecution);
+        assert!(matches!(
+            as_query(database_error("42P05")),
+            DbError::PreparedStatementConflict(_)
+        ));
+        assert!(matches!(
+            DbError::from(database_error("42P05")),
+            DbError::PreparedStatementConflict(_)
+        ));
+        assert!(matches!(
+            as_query(database_error("23505")),
+            DbError::QueryExecution(_)
+        ));
+        assert!(matches!(
+            as_query(sqlx::Error::PoolTimedOut),
+            DbError::PoolExhausted { timeout: t } if t == timeout
+        ));
+    }
+
+    #[test]
+    fn missing_relations_are_not_reported_as_catalog_changes() {
+        // Only the introspection retry path reads these as a catalog change.
+        assert!(matches!(
+            DbError::from(database_error("42P01")),
+            DbError::Connection(_)
+        ));
+    }
+
+    #[test]
+    fn connect_timeouts_are_connection_errors() {
+        assert!(matches!(
+            DbError::connect(sqlx::Error::PoolTimedOut),
+            DbError::Connection(_)
+        ));
+    }
+}
+
+#[cfg(feature = "http")]
+mod http {
+    use super::DbError;
+    use axum::{
+        Json,
+        http::StatusCode,
+        response::{IntoResponse, Response},
+    };
+    use serde_json::json;
+    use tracing::error;
+
+    impl DbError {
+        /// The HTTP status a handler failing with this error should answer with.
+        /// Constraint violations are conflicts with existing data.
+        pub fn status_code(&self) -> StatusCode {
+            match self {
+                DbError::NotFound(_) => StatusCode::NOT_FOUND,
+                DbError::InvalidInput(_) | DbError::Ambiguous(_) => StatusCode::BAD_REQUEST,
+                DbError::PoolExhausted { .. } => StatusCode::SERVICE_UNAVAILABLE,
+                // Errors converted with `?` end up in `Connection`, so look at both.
+                DbError::QueryExecution(_) | DbError::Connection(_)
+                    if self.is_constraint_violation() =>
+                {
+                    StatusCode::CONFLICT
+                }
+                _ => StatusCode::INTERNAL_SERVER_ERROR,
+            }
+        }
+
+        /// Whether the database rejected a statement for breaking a constraint
+        /// (SQLSTATE class 23: unique, foreign key, check, not-null, ...).
+        fn is_constraint_violation(&self) -> bool {
+            self.sqlstate().is_some_and(|code| code.starts_with("23"))
+        }
+    }
+
+    // Server errors are logged and answered with just their status, so connection
+    // details and SQL don't leak to clients.
+    impl IntoResponse for DbError {
+        fn into_response(self) -> Response {
+            let status = self.status_code();
+            let message = if status.is_server_error() {
+                error!("{}", self);
+                status.canonical_reason().unwrap_or("Error").to_string()
+            } else {
+                self.to_string()
+            };
+            let mut body = json!({ "status": status.as_u16(), "error": message });
+            if let Some(code) = self.sqlstate() {
+                body["code"] = code.into();
+            }
+            (status, Json(body)).into_response()
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use crate::error::tests::database_error;
+        use std::time::Duration;
+
+        #[test]
+        fn errors_map_to_http_statuses() {
+            let cases = [
+                (DbError::NotFound("x".into()), StatusCode::NOT_FOUND),
+                (DbError::InvalidInput("x".into()), StatusCode::BAD_REQUEST),
+                (DbError::Ambiguous("x".into()), StatusCode::BAD_REQUEST),
+                (
+                    DbError::PoolExhausted {
+                        timeout: Duration::from_secs(1),
+                    },
+                    StatusCode::SERVICE_UNAVAILABLE,
+                ),
+                (database_error("23505").into(), StatusCode::CONFLICT),
+                (
+                    DbError::QueryExecution(database_error("23503")),
+                    StatusCode::CONFLICT,
+                ),
+                (
+                    database_error("42P01").into(),
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                ),
+                (
+                    DbError::Config("x".into()),
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                ),
+                (
+                    DbError::TypeMapping("x".into()),
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                ),
+            ];
+            for (error, status) in cases {
+                assert_eq!(error.status_code(), status, "{}", error);
+            }
+        }
+
+        #[tokio::test]
+        async fn server_error_bodies_hide_the_details() {
+            let body = |error: DbError| async {
+                let response = error.into_response();
+                let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+                    .await
+                    .unwrap();
+                serde_json::from_slice::<serde_json::Value>(&bytes).unwrap()
+            };
+            assert_eq!(
+                body(DbError::Config("password=hunter2".into())).await,
+                json!({ "status": 500, "error": "Internal Server Error" })
+            );
+            assert_eq!(
+                body(database_error("23505").into()).await,
+                json!({
+                    "status": 409,
+                    "error": "SQLx connection error: error returned from database: error with SQLSTATE 23505",
+                    "code": "23505",
+                })
+            );
+        }
+    }
+}
@@ -0,0 +1,49 @@
+// axion-db/src/executor.rs
+use crate::error::{DbError, DbResult};
+use async_trait::async_trait;
+use sqlx::{AnyPool, FromRow, any::AnyRow};
+
+/// A minimal, object-safe abstraction over "run this SQL and decode the rows".
+///
+/// Introspectors issue their queries through this trait instead of calling
+/// `sqlx::query_as` against the pool directly, so the row-handling logic can be
+/// exercised against an in-memory fake that returns canned rows.
+///
+/// The trait is generic over the row type rather than its methods, which keeps
+/// `dyn QueryExecutor<R>` usable as a trait object.
+#[async_trait]
+pub trait QueryExecutor<R: Send>: Send + Sync {
+    /// Runs `sql` with the given positional text parameters and returns every row.
+    async fn fetch_all(&self, sql: &str, params: &[&str]) -> DbResult<Vec<R>>;
+
+    /// Runs `sql` with the given positional text parameters and returns exactly one row.
+    async fn fetch_one(&self, sql: &str, params: &[&str]) -> DbResult<R>;
+}
+
+#[async_trait]
+impl<R> QueryExecutor<R> for AnyPool
+where
+    R: for<'r> FromRow<'r, AnyRow> + Send + Unpin,
+{
+    async fn fetch_all(&self, sql: &str, params: &[&str]) -> DbResult<Vec<R>> {
+        let mut query = sqlx::query_as::<_, R>(sql);
+        for param in params {
+            query = query.bind(*param);
+        }
+        query
+            .fetch_all(self)
+            .await
+            .map_err(|e| DbError::from_pool(e, self))
+    }
+
+    async fn fetch_one(&self, sql: &str, params: &[&str]) -> DbResult<R> {
+        let mut query = sqlx::query_as::<_, R>(sql);
+        for param in params {
+            query = query.bind(*param);
+        }
+        query
+            .fetch_one(self)
+            .await
+            .map_err(|e| DbError::from_pool(e, self))
+    }
+}
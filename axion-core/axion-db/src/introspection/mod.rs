@@ -3,30 +3,228 @@ use crate::{
     client::DbClient,
     config::DatabaseType,
     error::{DbError, DbResult},
-    metadata::{DatabaseMetadata, EnumMetadata, SchemaMetadata, TableMetadata, ViewMetadata},
+    metadata::{
+        DatabaseMetadata, EnumMetadata, IntrospectionReport, SchemaMetadata, ServerInfo,
+        SkippedEntity, SurveyReport, TableMetadata, ViewMetadata,
+    },
 };
-use std::{collections::HashMap, sync::Arc};
+use futures::{StreamExt, TryStreamExt, stream};
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex, PoisonError},
+    time::{Duration, Instant},
+};
+use tracing::{info, warn};
+
+/// How many entities `Introspector::introspect_many` fetches at once, so a large
+/// batch doesn't drain the connection pool.
+pub const MAX_CONCURRENT_INTROSPECTIONS: usize = 4;
 
 // --- Implementations for each dialect ---
+#[cfg(feature = "postgres")]
 pub mod postgres;
 // pub mod mysql; // Future
 
+/// A progress notification emitted while introspection runs.
+/// Tables and views are both reported through the `Table*` events.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ProgressEvent {
+    SchemaStarted {
+        schema: String,
+        /// Zero-based position of this schema in the requested list.
+        index: usize,
+        total: usize,
+    },
+    SchemaFinished {
+        schema: String,
+        elapsed: Duration,
+        success: bool,
+    },
+    TableStarted {
+        schema: String,
+        table: String,
+    },
+    TableFinished {
+        schema: String,
+        table: String,
+        success: bool,
+    },
+    /// A schema, table or view could not be introspected and was left out.
+    Skipped(SkippedEntity),
+}
+
+/// The callback type used to report `ProgressEvent`s.
+pub type ProgressCallback<'a> = &'a (dyn Fn(ProgressEvent) + Send + Sync);
+
 /// The main Introspector trait that all database-specific introspectors must implement.
 #[async_trait::async_trait]
 pub trait Introspector: Send + Sync {
+    /// Lists the user schemas of the catalog (database) the client is connected to.
+    /// Schemas belonging to other catalogs on the same server are never included.
     async fn list_user_schemas(&self) -> DbResult<Vec<String>>;
+    /// Returns the name of the catalog (database) the client is connected to.
+    async fn current_catalog(&self) -> DbResult<String>;
+    /// Reads the server version, its encoding and the connected database's name.
+    async fn server_info(&self) -> DbResult<ServerInfo>;
+    /// Lists every catalog (database) on the server that the current role can connect to.
+    async fn list_catalogs(&self) -> DbResult<Vec<String>>;
+    /// Lists the names of the tables and views in a schema, without introspecting them.
+    async fn list_relation_names(&self, schema_name: &str) -> DbResult<Vec<String>>;
+    /// Counts the tables, views, enums and functions in each schema without fetching
+    /// any columns, as a cheap size estimate before a full introspection.
+    async fn survey(&self, schemas: &[String]) -> DbResult<SurveyReport>;
+    /// Introspects only what an entity-relationship diagram needs: the tables of each
+    /// schema with their column names, types, nullability and primary and foreign keys.
+    /// Views, comments, defaults, indexes and user-defined types are not fetched, so
+    /// enum and composite columns are not resolved. Much faster than `introspect`.
+    async fn introspect_graph(&self, schemas: &[String]) -> DbResult<DatabaseMetadata>;
     async fn introspect(&self, schemas: &[String]) -> DbResult<DatabaseMetadata>;
+
+    /// Whether `introspect_with_progress` should go on to introspect the schemas that
+    /// foreign keys point into. Off unless the implementation's options turn it on.
+    fn includes_referenced_schemas(&self) -> bool {
+        false
+    }
+
+    /// Introspects the given schemas one by one, recording how long each one took.
+    /// Schemas, tables and views that fail to introspect are logged, left out, and
+    /// listed in the report's `skipped`. Foreign keys into schemas that were not
+    /// introspected are logged and listed in the report's `dangling_relationships`.
+    async fn introspect_with_report(
+        &self,
+        schemas: &[String],
+    ) -> DbResult<(DatabaseMetadata, IntrospectionReport)> {
+        self.introspect_with_progress(schemas, &|_| {}).await
+    }
+
+    /// Like `introspect_with_report`, but calls `progress` as each schema and table
+    /// starts and finishes, so long-running introspections can be displayed.
+    async fn introspect_with_progress(
+        &self,
+        schemas: &[String],
+        progress: ProgressCallback<'_>,
+    ) -> DbResult<(DatabaseMetadata, IntrospectionReport)> {
+        let total_start = Instant::now();
+        let mut db_meta = DatabaseMetadata::default();
+        let mut report = IntrospectionReport::default();
+        match self.server_info().await {
+            Ok(server_info) => db_meta.server_info = Some(server_info),
+            Err(e) => warn!("Could not read the server version: {}", e),
+        }
+
+        // Skips are reported through the progress callback, so collect them on the way past.
+        let skipped = Mutex::new(Vec::new());
+        let progress = &|event: ProgressEvent| {
+            if let ProgressEvent::Skipped(entity) = &event {
+                skipped
+                    .lock()
+                    .unwrap_or_else(PoisonError::into_inner)
+                    .push(entity.clone());
+            }
+            progress(event);
+        };
+
+        // Referenced schemas are appended as they are discovered, when that is enabled.
+        let mut pending = schemas.to_vec();
+        let mut index = 0;
+        while let Some(schema_name) = pending.get(index).cloned() {
+            progress(ProgressEvent::SchemaStarted {
+                schema: schema_name.clone(),
+                index,
+                total: pending.len(),
+            });
+            let schema_start = Instant::now();
+            let success = match self
+                .introspect_schema_with_progress(&schema_name, progress)
+                .await
+            {
+                Ok(schema_meta) => {
+                    db_meta.schemas.insert(schema_name.clone(), schema_meta);
+                    true
+                }
+                Err(e) => {
+                    warn!("Could not introspect schema '{}': {}", schema_name, e);
+                    progress(ProgressEvent::Skipped(SkippedEntity {
+                        schema: schema_name.clone(),
+                        name: None,
+                        reason: e.to_string(),
+                    }));
+                    false
+                }
+            };
+            let elapsed = schema_start.elapsed();
+            info!(schema = %schema_name, ?elapsed, "Schema introspection finished");
+            report.per_schema.insert(schema_name.clone(), elapsed);
+            progress(ProgressEvent::SchemaFinished {
+                schema: schema_name.clone(),
+                elapsed,
+                success,
+            });
+
+            index += 1;
+            if index == pending.len() && self.includes_referenced_schemas() {
+                for edge in db_meta.dangling_relationships() {
+                    if !pending.contains(&edge.to_schema) {
+                        info!(
+                            "Including schema '{}', referenced by {}",
+                            edge.to_schema, edge
+                        );
+                        pending.push(edge.to_schema);
+                    }
+                }
+            }
+        }
+
+        report.dangling_relationships = db_meta.dangling_relationships();
+        for edge in &report.dangling_relationships {
+            warn!(
+                "Foreign key {} points outside the introspected schemas",
+                edge
+            );
+        }
+        report.skipped = skipped.into_inner().unwrap_or_else(PoisonError::into_inner);
+        report.total_duration = total_start.elapsed();
+        report.finished_at = Some(chrono::Utc::now());
+        Ok((db_meta, report))
+    }
     async fn introspect_schema(&self, schema_name: &str) -> DbResult<SchemaMetadata>;
+
+    /// Introspects a single schema, reporting per-table progress.
+    /// The default implementation reports nothing below the schema level.
+    async fn introspect_schema_with_progress(
+        &self,
+        schema_name: &str,
+        progress: ProgressCallback<'_>,
+    ) -> DbResult<SchemaMetadata> {
+        self.introspect_schema(schema_name).await
+    }
     async fn introspect_table(
         &self,
         schema_name: &str,
         table_name: &str,
     ) -> DbResult<TableMetadata>;
+
+    /// Introspects the given `(schema, table)` pairs concurrently, running at most
+    /// `MAX_CONCURRENT_INTROSPECTIONS` at a time. Results are returned in input order;
+    /// the first failure aborts the whole batch.
+    async fn introspect_many(&self, entities: &[(String, String)]) -> DbResult<Vec<TableMetadata>> {
+        // The futures are built up front; mapping lazily inside the stream trips up the
+        // `Send` check async_trait needs.
+        let futures: Vec<_> = entities
+            .iter()
+            .map(|(schema, table)| self.introspect_table(schema, table))
+            .collect();
+        stream::iter(futures)
+            .buffered(MAX_CONCURRENT_INTROSPECTIONS)
+            .try_collect()
+            .await
+    }
     async fn introspect_view(&self, schema_name: &str, view_name: &str) -> DbResult<ViewMetadata>;
     async fn introspect_enums_for_schema(
         &self,
         schema_name: &str,
     ) -> DbResult<HashMap<String, EnumMetadata>>;
+    async fn introspect_enum(&self, schema_name: &str, enum_name: &str) -> DbResult<EnumMetadata>;
 }
 
 // ==============================================================================
@@ -34,8 +232,11 @@ pub trait Introspector: Send + Sync {
 // ==============================================================================
 
 /// A factory function that creates the correct, boxed introspector based on the database dialect.
+/// Returns `FeatureNotEnabled` if the crate was built without the dialect's feature.
 pub fn new_introspector(client: Arc<DbClient>) -> DbResult<Box<dyn Introspector>> {
+    client.config.db_type.ensure_enabled()?;
     match client.config.db_type {
+        #[cfg(feature = "postgres")]
         DatabaseType::Postgres => Ok(Box::new(postgres::PostgresIntrospector::new(client))),
         // Future dialects would be added here:
         // DatabaseType::Mysql => Ok(Box::new(mysql::MySqlIntrospector::new(client))),
@@ -44,3 +245,20 @@ pub fn new_introspector(client: Arc<DbClient>) -> DbResult<Box<dyn Introspector>
         )),
     }
 }
+
+/// Parses the various ways drivers and dialects report column nullability
+/// (`YES`/`NO`, `true`/`false`, `1`/`0`) into a `bool`.
+/// Unrecognized values are treated as nullable, which is the safer assumption.
+pub(crate) fn parse_nullable(value: &str) -> bool {
+    match value.trim().to_ascii_lowercase().as_str() {
+        "yes" | "true" | "1" | "y" | "t" => true,
+        "no" | "false" | "0" | "n" | "f" => false,
+        other => {
+            warn!(
+                "Unrecognized nullability value '{}', assuming nullable",
+                other
+            );
+            true
+        }
+    }
+}
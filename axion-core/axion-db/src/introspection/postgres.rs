@@ -1,14 +1,24 @@
 // axion-db/src/introspection/postgres.rs
 use crate::{
     client::DbClient,
+    config::IntrospectionOptionsConfig,
     error::{DbError, DbResult},
-    introspection::Introspector,
+    executor::QueryExecutor,
+    introspection::{
+        Introspector, MAX_CONCURRENT_INTROSPECTIONS, ProgressCallback, ProgressEvent,
+        parse_nullable,
+    },
     metadata::*,
-    types::{TypeMapper, postgres::PostgresTypeMapper},
+    types::{TypeMapper, UserDefinedTypes, postgres::PostgresTypeMapper},
 };
-use sqlx::FromRow;
-use std::{collections::HashMap, sync::Arc};
-use tracing::{info, instrument, warn};
+use futures::{Stream, StreamExt, TryStreamExt, stream};
+use sqlx::{AnyPool, FromRow};
+use std::{
+    collections::{HashMap, HashSet},
+    sync::{Arc, Mutex, OnceLock, PoisonError},
+    time::{Duration, Instant},
+};
+use tracing::{debug, info, instrument, trace, warn};
 
 // =================================================================================
 //  1. FromRow Structs (Unchanged)
@@ -19,69 +29,462 @@ struct TableAndViewRow {
     table_type: String,
 }
 
+/// A column's type the way `information_schema.columns` reports it, shared by every
+/// query that maps column types.
 #[derive(Debug, FromRow, Clone)]
-struct ColumnIntrospectionRow {
-    column_name: String,
+struct ColumnTypeRow {
     data_type: String,
+    canonical_sql_type: Option<String>,
+    udt_schema: String,
     udt_name: String,
+    domain_name: Option<String>,
+    array_dimensions: Option<i32>,
+}
+
+#[derive(Debug, FromRow, Clone)]
+struct ColumnIntrospectionRow {
+    column_name: String,
+    #[sqlx(flatten)]
+    column_type: ColumnTypeRow,
     is_nullable: String,
     column_default: Option<String>,
+    collation_name: Option<String>,
     column_comment: Option<String>,
-    is_primary_key: bool,
+    /// The column's 1-based position in the primary key, if it is part of it.
+    primary_key_position: Option<i32>,
+    is_identity: bool,
 }
 
-#[derive(Debug, FromRow)]
+#[derive(Debug, FromRow, Clone)]
 struct ForeignKeyIntrospectionRow {
     column_name: String,
     foreign_table_schema: String,
     foreign_table_name: String,
     foreign_column_name: String,
+    on_delete: String,
+    on_update: String,
 }
 
-#[derive(Debug, FromRow)]
+#[derive(Debug, FromRow, Clone)]
 struct EnumIntrospectionRow {
     enum_name: String,
     enum_value: String,
+    sort_order: f64,
+    enum_comment: Option<String>,
+}
+
+#[derive(Debug, FromRow)]
+struct CompositeFieldRow {
+    type_name: String,
+    field_name: String,
+    data_type: String,
+    field_udt_schema: String,
+    field_udt_name: String,
+}
+
+#[derive(Debug, FromRow, Clone)]
+struct TriggerIntrospectionRow {
+    trigger_name: String,
+    timing: String,
+    events: String,
+    function_name: String,
+    definition: String,
+}
+
+#[derive(Debug, FromRow, Clone)]
+struct IndexIntrospectionRow {
+    index_name: String,
+    columns: String,
+    is_unique: bool,
+    is_primary: bool,
+}
+
+#[derive(Debug, FromRow)]
+struct ViewDefinitionRow {
+    view_definition: Option<String>,
+    is_updatable: bool,
+    is_insertable: bool,
+    view_comment: Option<String>,
+}
+
+#[derive(Debug, FromRow, Default, Clone)]
+struct RelationInfoRow {
+    is_partitioned: bool,
+    partition_of: Option<String>,
+    partition_bound: Option<String>,
+    rls_enabled: bool,
+    comment: Option<String>,
+}
+
+#[derive(Debug, FromRow, Clone)]
+struct PolicyIntrospectionRow {
+    policy_name: String,
+    command: String,
+    roles: String,
+    using_expr: Option<String>,
+    check_expr: Option<String>,
+}
+
+#[derive(Debug, FromRow, Clone)]
+struct RoutineIntrospectionRow {
+    routine_oid: i64,
+    routine_name: String,
+    argument_types: String,
+    routine_kind: String,
+    return_data_type: Option<String>,
+    return_udt_schema: String,
+    return_udt_name: String,
+    routine_comment: Option<String>,
+}
+
+#[derive(Debug, FromRow, Clone)]
+struct ParameterIntrospectionRow {
+    routine_oid: i64,
+    parameter_name: String,
+    parameter_mode: String,
+    data_type: String,
+    udt_schema: String,
+    udt_name: String,
+    sql_type_name: String,
+    default_count: i32,
+}
+
+#[derive(Debug, FromRow)]
+struct SurveyRow {
+    tables: i64,
+    views: i64,
+    enums: i64,
+    functions: i64,
+}
+
+#[derive(Debug, FromRow)]
+struct GraphColumnRow {
+    table_name: String,
+    column_name: String,
+    #[sqlx(flatten)]
+    column_type: ColumnTypeRow,
+    is_nullable: bool,
+    /// The column's 1-based position in the primary key, if it is part of it.
+    primary_key_position: Option<i32>,
+}
+
+#[derive(Debug, FromRow)]
+struct GraphForeignKeyRow {
+    table_name: String,
+    column_name: String,
+    foreign_table_schema: String,
+    foreign_table_name: String,
+    foreign_column_name: String,
+    on_delete: String,
+    on_update: String,
+}
+
+#[derive(Debug, FromRow)]
+struct SystemColumnRow {
+    column_name: String,
+    type_name: String,
+    canonical_sql_type: String,
+}
+
+#[derive(Debug, FromRow)]
+struct TableStatisticsRow {
+    estimated_rows: i64,
+    total_bytes: i64,
+    index_bytes: i64,
+}
+
+/// Picks the executor that rows of type `R` are fetched through.
+trait ExecutorFor<R: Send> {
+    fn executor(&self) -> &dyn QueryExecutor<R>;
+}
+
+// Declares `Executors`, with one executor per kind of row the introspector reads.
+macro_rules! executors {
+    ( $( $field:ident: $row:ty ),* $(,)? ) => {
+        /// The executor each kind of introspection row is fetched through.
+        struct Executors {
+            $( $field: Arc<dyn QueryExecutor<$row>>, )*
+        }
+
+        impl Executors {
+            /// Fetches every kind of row through `executor`.
+            fn new<E>(executor: Arc<E>) -> Self
+            where
+                E: 'static $( + QueryExecutor<$row> )*,
+            {
+                Self {
+                    $( $field: executor.clone(), )*
+                }
+            }
+        }
+
+        $(
+            impl ExecutorFor<$row> for Executors {
+                fn executor(&self) -> &dyn QueryExecutor<$row> {
+                    self.$field.as_ref()
+                }
+            }
+        )*
+    };
+}
+
+executors! {
+    tables_and_views: TableAndViewRow,
+    columns: ColumnIntrospectionRow,
+    foreign_keys: ForeignKeyIntrospectionRow,
+    enums: EnumIntrospectionRow,
+    composite_fields: CompositeFieldRow,
+    triggers: TriggerIntrospectionRow,
+    indexes: IndexIntrospectionRow,
+    view_definitions: ViewDefinitionRow,
+    relation_info: RelationInfoRow,
+    policies: PolicyIntrospectionRow,
+    routines: RoutineIntrospectionRow,
+    parameters: ParameterIntrospectionRow,
+    surveys: SurveyRow,
+    graph_columns: GraphColumnRow,
+    graph_foreign_keys: GraphForeignKeyRow,
+    system_columns: SystemColumnRow,
+    statistics: TableStatisticsRow,
+    user_types: (String, String, String),
+    server_info: (String, i32, String, String),
+    names: (String,),
+}
+
+/// Whether an error for an entity that was just listed means the catalog changed under us:
+/// the entity is gone, a relation or schema vanished mid-query (SQLSTATE 42P01, 3F000),
+/// or a catalog row was removed while a query was reading it.
+fn is_catalog_change(err: &DbError) -> bool {
+    let message = err.to_string();
+    matches!(err, DbError::CatalogChanged(_) | DbError::NotFound(_))
+        || matches!(err.sqlstate().as_deref(), Some("42P01" | "3F000"))
+        || message.contains("could not open relation with OID")
+        || message.contains("cache lookup failed")
+}
+
+/// Decodes a list of identifiers sent as a JSON array (via `array_to_json`).
+/// Identifiers may contain commas, so a delimited string can't be split reliably.
+fn parse_name_list(json: &str) -> DbResult<Vec<String>> {
+    serde_json::from_str(json)
+        .map_err(|e| DbError::Introspection(format!("Invalid identifier list '{}': {}", json, e)))
+}
+
+/// Maps a `pg_constraint.confdeltype`/`confupdtype` code to its action.
+fn referential_action(code: &str) -> ReferentialAction {
+    match code {
+        "r" => ReferentialAction::Restrict,
+        "c" => ReferentialAction::Cascade,
+        "n" => ReferentialAction::SetNull,
+        "d" => ReferentialAction::SetDefault,
+        _ => ReferentialAction::NoAction,
+    }
+}
+
+/// Whether the database fills the column from a sequence: `serial` columns default to
+/// `nextval(...)`, while identity columns are flagged by `information_schema`.
+fn is_auto_increment(default: Option<&str>, is_identity: bool) -> bool {
+    is_identity || default.is_some_and(|d| d.trim_start().starts_with("nextval("))
+}
+
+/// The names of primary key columns collected as `(position in the key, name)`, in key
+/// order, which can differ from the order of the table's columns.
+fn in_key_order(mut columns: Vec<(i32, String)>) -> Vec<String> {
+    columns.sort();
+    columns.into_iter().map(|(_, name)| name).collect()
 }
 
 // =================================================================================
 //  2. The Introspector Implementation
 // =================================================================================
 
+// First server versions (as `server_version_num`) with the catalog features the
+// queries rely on. Older servers get fallback queries that leave the feature's
+// metadata empty instead of failing.
+/// `pg_class.relrowsecurity` and `pg_policies`.
+const PG_ROW_SECURITY: u32 = 90500;
+/// Declarative partitioning: `relkind = 'p'`, `relispartition` and `relpartbound`.
+const PG_PARTITIONING: u32 = 100000;
+/// `pg_proc.prokind` (procedures) and `pg_index.indnkeyatts` (`INCLUDE` columns).
+const PG_PROKIND: u32 = 110000;
+
+/// How long to wait before the first retry of a schema whose catalog changed mid-run.
+/// Each further retry waits that much longer.
+const CATALOG_CHANGE_BACKOFF: Duration = Duration::from_millis(100);
+
 pub struct PostgresIntrospector {
     client: Arc<DbClient>,
-    type_mapper: PostgresTypeMapper,
+    /// The mapper used for any schema without an entry in `schema_type_mappers`.
+    type_mapper: Box<dyn TypeMapper>,
+    /// Per-schema overrides, keyed by schema name.
+    schema_type_mappers: HashMap<String, Box<dyn TypeMapper>>,
+    options: IntrospectionOptionsConfig,
+    /// The server's `server_version_num`, read on first use.
+    server_version: OnceLock<u32>,
+    /// The executor each kind of row is fetched through: the read pool for all of them.
+    executors: Executors,
 }
 
 impl PostgresIntrospector {
     pub fn new(client: Arc<DbClient>) -> Self {
+        let options = client
+            .config
+            .introspection_options
+            .clone()
+            .unwrap_or_default();
         Self {
+            executors: Executors::new(client.read_pool.clone()),
             client,
-            type_mapper: PostgresTypeMapper,
+            type_mapper: Box::new(PostgresTypeMapper),
+            schema_type_mappers: HashMap::new(),
+            options,
+            server_version: OnceLock::new(),
+        }
+    }
+
+    /// Replaces the default type mapper used for schemas without an override.
+    pub fn with_type_mapper(mut self, mapper: impl TypeMapper + 'static) -> Self {
+        self.type_mapper = Box::new(mapper);
+        self
+    }
+
+    /// Uses `mapper` for columns in `schema_name` instead of the default mapper.
+    pub fn with_schema_type_mapper(
+        mut self,
+        schema_name: impl Into<String>,
+        mapper: impl TypeMapper + 'static,
+    ) -> Self {
+        self.schema_type_mappers
+            .insert(schema_name.into(), Box::new(mapper));
+        self
+    }
+
+    /// The type mapper for `schema_name`, falling back to the default mapper.
+    fn type_mapper_for(&self, schema_name: &str) -> &dyn TypeMapper {
+        self.schema_type_mappers
+            .get(schema_name)
+            .map_or(self.type_mapper.as_ref(), |mapper| mapper.as_ref())
+    }
+
+    /// The server's version number, used to pick queries the server understands.
+    /// If it cannot be read, a current server is assumed.
+    async fn server_version(&self) -> u32 {
+        if let Some(version) = self.server_version.get() {
+            return *version;
+        }
+        let version = match self.server_info().await {
+            Ok(server_info) => server_info.version_num,
+            Err(e) => {
+                warn!(
+                    "Could not read the server version, assuming a current server: {}",
+                    e
+                );
+                u32::MAX
+            }
+        };
+        *self.server_version.get_or_init(|| version)
+    }
+
+    /// Logs a table, view or statistics query that is being skipped: at `warn` by default,
+    /// or at `debug` with the `quiet_skips` option. Skips are reported either way.
+    fn log_skip(&self, message: std::fmt::Arguments<'_>) {
+        if self.options.quiet_skips {
+            debug!("{}", message);
+        } else {
+            warn!("{}", message);
         }
     }
 
+    /// Introspects the tables of `schema_name` in name order, yielding each one as soon as
+    /// it is ready, with up to `MAX_CONCURRENT_INTROSPECTIONS` in flight. Views are left
+    /// out. A table that fails yields its error and the stream goes on; if the tables
+    /// cannot be listed, the stream yields that error alone.
+    pub fn stream_tables<'a>(
+        &'a self,
+        schema_name: &'a str,
+    ) -> impl Stream<Item = DbResult<TableMetadata>> + Send + 'a {
+        let listing = async move {
+            let (entities, user_types) = tokio::try_join!(
+                self.list_tables_and_views(schema_name),
+                self.list_user_types()
+            )?;
+            let tables: Vec<String> = entities
+                .into_iter()
+                .filter(|entity| entity.table_type == "BASE TABLE")
+                .map(|entity| entity.table_name)
+                .collect();
+            DbResult::Ok((tables, Arc::new(user_types)))
+        };
+        stream::once(listing)
+            .map_ok(move |(tables, user_types)| {
+                stream::iter(tables)
+                    .map(move |table_name| {
+                        let user_types = user_types.clone();
+                        async move {
+                            let table = self.introspect_table_with_types(
+                                schema_name,
+                                &table_name,
+                                &user_types,
+                            );
+                            self.with_timeout(schema_name, &table_name, table).await
+                        }
+                    })
+                    .buffered(MAX_CONCURRENT_INTROSPECTIONS)
+            })
+            .try_flatten()
+    }
+
     // --- Helper Methods using our validated queries ---
 
-    #[instrument(skip(self), name = "list_db_entities")]
+    /// Runs an introspection future under the configured introspection timeout, if any.
+    /// `entity` names what was being introspected, for the error message.
+    async fn with_timeout<T>(
+        &self,
+        schema_name: &str,
+        entity: &str,
+        future: impl Future<Output = DbResult<T>>,
+    ) -> DbResult<T> {
+        match self.options.timeout_seconds {
+            Some(seconds) => tokio::time::timeout(Duration::from_secs(seconds), future)
+                .await
+                .map_err(|_| DbError::IntrospectionTimeout {
+                    schema: schema_name.to_string(),
+                    entity: entity.to_string(),
+                })?,
+            None => future.await,
+        }
+    }
+
+    /// The executor that introspection queries decoding into `R` go through.
+    fn executor<R: Send>(&self) -> &dyn QueryExecutor<R>
+    where
+        Executors: ExecutorFor<R>,
+    {
+        self.executors.executor()
+    }
+
+    #[instrument(skip(self), name = "list_db_entities", level = "debug")]
     async fn list_tables_and_views(&self, schema_name: &str) -> DbResult<Vec<TableAndViewRow>> {
-        let query = "
+        let privilege_filter = if self.options.accessible_only {
+            "AND has_table_privilege(quote_ident(table_schema) || '.' || quote_ident(table_name), 'SELECT')"
+        } else {
+            ""
+        };
+        let query = format!(
+            "
             SELECT
                 table_name::TEXT,
                 table_type::TEXT
             FROM information_schema.tables
             WHERE table_schema = $1
+            {privilege_filter}
             ORDER BY table_type, table_name;
-        ";
-        sqlx::query_as(query)
-            .bind(schema_name)
-            .fetch_all(&*self.client.pool)
-            .await
-            .map_err(DbError::from)
+        "
+        );
+        self.executor().fetch_all(&query, &[schema_name]).await
     }
 
     // (get_foreign_keys_for_table remains unchanged)
-    #[instrument(skip(self), name = "get_foreign_keys")]
+    #[instrument(skip(self), name = "get_foreign_keys", level = "trace")]
     async fn get_foreign_keys_for_table(
         &self,
         schema_name: &str,
@@ -92,20 +495,24 @@ impl PostgresIntrospector {
                 kcu.column_name::TEXT,
                 ccu.table_schema::TEXT AS foreign_table_schema,
                 ccu.table_name::TEXT AS foreign_table_name,
-                ccu.column_name::TEXT AS foreign_column_name
+                ccu.column_name::TEXT AS foreign_column_name,
+                con.confdeltype::TEXT AS on_delete,
+                con.confupdtype::TEXT AS on_update
             FROM information_schema.table_constraints AS tc
             JOIN information_schema.key_column_usage AS kcu
                 ON tc.constraint_name = kcu.constraint_name AND tc.constraint_schema = kcu.constraint_schema
             JOIN information_schema.constraint_column_usage AS ccu
                 ON ccu.constraint_name = tc.constraint_name AND ccu.constraint_schema = tc.constraint_schema
+            JOIN pg_catalog.pg_namespace AS cn ON cn.nspname = tc.constraint_schema
+            JOIN pg_catalog.pg_constraint AS con
+                ON con.connamespace = cn.oid AND con.conname = tc.constraint_name
             WHERE tc.constraint_type = 'FOREIGN KEY'
             AND tc.table_schema = $1
             AND tc.table_name = $2
         "#;
-        let rows: Vec<ForeignKeyIntrospectionRow> = sqlx::query_as(query)
-            .bind(schema_name)
-            .bind(table_name)
-            .fetch_all(&*self.client.pool)
+        let rows: Vec<ForeignKeyIntrospectionRow> = self
+            .executor()
+            .fetch_all(query, &[schema_name, table_name])
             .await?;
         Ok(rows
             .into_iter()
@@ -116,261 +523,1749 @@ impl PostgresIntrospector {
                         schema: row.foreign_table_schema,
                         table: row.foreign_table_name,
                         column: row.foreign_column_name,
+                        on_delete: referential_action(&row.on_delete),
+                        on_update: referential_action(&row.on_update),
                     },
                 )
             })
             .collect())
     }
-}
 
-// =================================================================================
-//  3. The Main Introspector Trait Implementation (Now with View/Enum Logic)
-// =================================================================================
-
-#[async_trait::async_trait]
-impl Introspector for PostgresIntrospector {
-    #[instrument(skip(self), name = "introspect_database")]
-    async fn introspect(&self, schemas: &[String]) -> DbResult<DatabaseMetadata> {
-        info!(
-            "Starting full database introspection for schemas: {:?}",
-            schemas
-        );
-        let mut db_meta = DatabaseMetadata::default();
-        for schema_name in schemas {
-            match self.introspect_schema(schema_name).await {
-                Ok(schema_meta) => {
-                    db_meta.schemas.insert(schema_name.clone(), schema_meta);
-                }
-                Err(e) => warn!("Could not introspect schema '{}': {}", schema_name, e),
+    /// Groups one-row-per-label enum query results into `EnumMetadata` entries.
+    fn group_enum_rows(
+        schema_name: &str,
+        rows: Vec<EnumIntrospectionRow>,
+    ) -> HashMap<String, EnumMetadata> {
+        let mut enums = HashMap::new();
+        for row in rows {
+            let enum_meta = enums
+                .entry(row.enum_name.clone())
+                .or_insert_with(|| EnumMetadata {
+                    name: row.enum_name,
+                    schema: schema_name.to_string(),
+                    comment: row.enum_comment,
+                    is_declaration_order: true,
+                    ..Default::default()
+                });
+            // `CREATE TYPE` and `ADD VALUE` (at the end) assign whole sort orders;
+            // only `ADD VALUE ... BEFORE/AFTER` produces fractional ones.
+            if row.sort_order.fract() != 0.0 {
+                enum_meta.is_declaration_order = false;
             }
+            enum_meta.values.push(row.enum_value);
         }
-        info!("Database introspection complete.");
-        Ok(db_meta)
+        enums
     }
 
-    #[instrument(skip(self), name = "introspect_schema")]
-    async fn introspect_schema(&self, schema_name: &str) -> DbResult<SchemaMetadata> {
-        let mut schema_meta = SchemaMetadata {
-            name: schema_name.to_string(),
-            ..Default::default()
-        };
-
-        // Fetch all entities and enums for the schema concurrently
-        let (entities_result, enums_result) = tokio::join!(
-            self.list_tables_and_views(schema_name),
-            self.introspect_enums_for_schema(schema_name)
-        );
+    /// Lists every enum and composite type in the database, across all schemas, so
+    /// those columns can be told apart from other user-defined types.
+    #[instrument(skip(self), name = "list_user_types", level = "debug")]
+    async fn list_user_types(&self) -> DbResult<UserDefinedTypes> {
+        // Composite types also exist for every table's row type; only keep the
+        // standalone ones created with `CREATE TYPE ... AS (...)` (relkind 'c').
+        let query = "
+            SELECT n.nspname::TEXT, t.typname::TEXT, t.typtype::TEXT
+            FROM pg_catalog.pg_type t
+            JOIN pg_catalog.pg_namespace n ON n.oid = t.typnamespace
+            LEFT JOIN pg_catalog.pg_class c ON c.oid = t.typrelid
+            WHERE t.typtype = 'e' OR (t.typtype = 'c' AND c.relkind = 'c');
+        ";
+        let rows: Vec<(String, String, String)> = self.executor().fetch_all(query, &[]).await?;
+        let mut user_types = UserDefinedTypes::default();
+        for (schema, name, kind) in rows {
+            let name = QualifiedName::new(schema, name);
+            match kind.as_str() {
+                "e" => user_types.enums.insert(name),
+                _ => user_types.composites.insert(name),
+            };
+        }
+        Ok(user_types)
+    }
 
-        schema_meta.enums = enums_result?;
+    /// Fetches the fields of every standalone composite type in a schema.
+    #[instrument(skip(self), name = "list_composite_fields", level = "trace")]
+    async fn list_composite_fields(&self, schema_name: &str) -> DbResult<Vec<CompositeFieldRow>> {
+        // information_schema.attributes only covers standalone composite types.
+        let query = "
+            SELECT
+                udt_name::TEXT AS type_name,
+                attribute_name::TEXT AS field_name,
+                data_type::TEXT,
+                attribute_udt_schema::TEXT AS field_udt_schema,
+                attribute_udt_name::TEXT AS field_udt_name
+            FROM information_schema.attributes
+            WHERE udt_schema = $1
+            ORDER BY udt_name, ordinal_position;
+        ";
+        self.executor().fetch_all(query, &[schema_name]).await
+    }
 
-        for entity in entities_result? {
-            if entity.table_type == "BASE TABLE" {
-                match self.introspect_table(schema_name, &entity.table_name).await {
-                    Ok(table_md) => {
-                        schema_meta.tables.insert(entity.table_name, table_md);
-                    }
-                    Err(e) => warn!(
-                        "Skipping table {}.{}: {}",
-                        schema_name, entity.table_name, e
-                    ),
-                }
-            } else if entity.table_type == "VIEW" {
-                match self.introspect_view(schema_name, &entity.table_name).await {
-                    Ok(view_md) => {
-                        schema_meta.views.insert(entity.table_name, view_md);
-                    }
-                    Err(e) => warn!("Skipping view {}.{}: {}", schema_name, entity.table_name, e),
-                }
-            }
+    /// Groups composite field rows into `CompositeTypeMetadata`, keyed by type name.
+    fn group_composite_rows(
+        &self,
+        schema_name: &str,
+        rows: Vec<CompositeFieldRow>,
+        user_types: &UserDefinedTypes,
+    ) -> HashMap<String, CompositeTypeMetadata> {
+        let mut composites: HashMap<String, CompositeTypeMetadata> = HashMap::new();
+        for row in rows {
+            let axion_type = self.type_mapper_for(schema_name).sql_to_axion(
+                &row.data_type,
+                Some(&row.field_udt_schema),
+                Some(&row.field_udt_name),
+                user_types,
+            );
+            composites
+                .entry(row.type_name.clone())
+                .or_insert_with(|| CompositeTypeMetadata {
+                    name: row.type_name,
+                    schema: schema_name.to_string(),
+                    fields: Vec::new(),
+                })
+                .fields
+                .push((row.field_name, axion_type));
         }
-
-        Ok(schema_meta)
+        composites
     }
 
-    #[instrument(skip(self, table_name), name = "introspect_table")]
-    async fn introspect_table(
+    #[instrument(
+        skip(self, table_name, user_types),
+        name = "introspect_table",
+        level = "trace"
+    )]
+    async fn introspect_table_with_types(
         &self,
         schema_name: &str,
         table_name: &str,
+        user_types: &UserDefinedTypes,
     ) -> DbResult<TableMetadata> {
         let columns_query = r#"
             SELECT
                 c.column_name::TEXT,
                 c.data_type::TEXT,
+                c.udt_schema::TEXT,
                 c.udt_name::TEXT,
+                c.domain_name::TEXT,
                 c.is_nullable::TEXT,
                 c.column_default,
+                c.collation_name::TEXT,
                 pg_catalog.col_description((quote_ident(c.table_schema) || '.' || quote_ident(c.table_name))::regclass::oid, c.ordinal_position) AS column_comment,
-                EXISTS (
-                    SELECT 1 FROM information_schema.table_constraints tc
+                (
+                    SELECT a.attndims::INT4 FROM pg_catalog.pg_attribute a
+                    WHERE a.attrelid = (quote_ident(c.table_schema) || '.' || quote_ident(c.table_name))::regclass AND a.attname = c.column_name
+                ) AS array_dimensions,
+                (
+                    SELECT pg_catalog.format_type(a.atttypid, a.atttypmod) FROM pg_catalog.pg_attribute a
+                    WHERE a.attrelid = (quote_ident(c.table_schema) || '.' || quote_ident(c.table_name))::regclass AND a.attname = c.column_name
+                ) AS canonical_sql_type,
+                (
+                    SELECT kcu.ordinal_position::INT4 FROM information_schema.table_constraints tc
                     JOIN information_schema.key_column_usage kcu ON tc.constraint_name = kcu.constraint_name AND tc.constraint_schema = kcu.constraint_schema
                     WHERE tc.table_schema = c.table_schema AND tc.table_name = c.table_name AND kcu.column_name = c.column_name AND tc.constraint_type = 'PRIMARY KEY'
-                ) AS is_primary_key
+                ) AS primary_key_position,
+                (c.is_identity = 'YES') AS is_identity
             FROM information_schema.columns c
             WHERE c.table_schema = $1 AND c.table_name = $2
             ORDER BY c.ordinal_position;
         "#;
 
-        let (columns_result, fks_result) = tokio::join!(
-            sqlx::query_as::<_, ColumnIntrospectionRow>(columns_query)
-                .bind(schema_name)
-                .bind(table_name)
-                .fetch_all(&*self.client.pool),
-            self.get_foreign_keys_for_table(schema_name, table_name)
+        let params = [schema_name, table_name];
+        let (
+            columns_result,
+            fks_result,
+            relation_result,
+            triggers_result,
+            indexes_result,
+            policies_result,
+        ) = tokio::join!(
+            self.executor::<ColumnIntrospectionRow>()
+                .fetch_all(columns_query, &params),
+            async {
+                let start = Instant::now();
+                let result = self
+                    .get_foreign_keys_for_table(schema_name, table_name)
+                    .await;
+                trace!(
+                    table = %QualifiedName::new(schema_name, table_name),
+                    elapsed = ?start.elapsed(),
+                    "Introspected foreign keys"
+                );
+                result
+            },
+            self.get_relation_info(schema_name, table_name),
+            self.get_triggers_for_table(schema_name, table_name),
+            self.get_indexes_for_table(schema_name, table_name),
+            self.get_policies_for_table(schema_name, table_name)
         );
 
         let column_rows = columns_result?;
         let foreign_keys = fks_result?;
+        let relation_info = relation_result?;
+        let triggers = triggers_result?;
+        let indexes = indexes_result?;
+        let policies = policies_result?;
 
         if column_rows.is_empty() {
-            return Err(DbError::Introspection(format!(
-                "Table {}.{} not found or has no columns",
+            return Err(DbError::NotFound(format!(
+                "Table '{}.{}' (or it has no columns)",
                 schema_name, table_name
             )));
         }
 
         let mut columns = Vec::new();
-        let mut primary_key_columns = Vec::new();
+        let mut primary_key = Vec::new();
 
         for row in column_rows {
-            if row.is_primary_key {
-                primary_key_columns.push(row.column_name.clone());
+            if let Some(position) = row.primary_key_position {
+                primary_key.push((position, row.column_name.clone()));
             }
             let foreign_key = foreign_keys.get(&row.column_name).cloned();
+            let axion_type = self.map_column_type(schema_name, &row.column_type, user_types);
+            let is_auto_increment =
+                is_auto_increment(row.column_default.as_deref(), row.is_identity);
+            let column_type = row.column_type;
 
             columns.push(ColumnMetadata {
                 name: row.column_name,
-                sql_type_name: row.data_type.clone(),
-                axion_type: self
-                    .type_mapper
-                    .sql_to_axion(&row.data_type, Some(&row.udt_name)),
-                is_nullable: row.is_nullable.to_lowercase() == "yes",
-                is_primary_key: row.is_primary_key,
+                canonical_sql_type: column_type
+                    .canonical_sql_type
+                    .unwrap_or_else(|| column_type.data_type.clone()),
+                sql_type_name: column_type.data_type,
+                axion_type,
+                is_nullable: parse_nullable(&row.is_nullable),
+                is_primary_key: row.primary_key_position.is_some(),
+                is_auto_increment,
                 default_value: row.column_default,
+                collation: row.collation_name,
                 comment: row.column_comment,
                 foreign_key,
+                is_system: false,
             });
         }
 
+        if self.options.include_system_columns {
+            columns.extend(
+                self.get_system_columns(schema_name, table_name, user_types)
+                    .await?,
+            );
+        }
+
+        let statistics = if self.options.include_statistics {
+            match self.get_table_statistics(schema_name, table_name).await {
+                Ok(stats) => Some(stats),
+                Err(e) => {
+                    self.log_skip(format_args!(
+                        "Could not fetch statistics for {}.{}: {}",
+                        schema_name, table_name, e
+                    ));
+                    None
+                }
+            }
+        } else {
+            None
+        };
+
         Ok(TableMetadata {
             name: table_name.to_string(),
             schema: schema_name.to_string(),
             columns,
-            primary_key_columns,
-            comment: None, // Table comments would require another small query
+            primary_key_columns: in_key_order(primary_key),
+            comment: relation_info.comment,
+            statistics,
+            is_partitioned: relation_info.is_partitioned,
+            partition_of: relation_info.partition_of,
+            partition_bound: relation_info.partition_bound,
+            triggers,
+            indexes,
+            rls_enabled: relation_info.rls_enabled,
+            policies,
         })
     }
 
-    // =================================== NEW METHODS ===================================
-
-    #[instrument(skip(self, view_name), name = "introspect_view")]
-    async fn introspect_view(&self, schema_name: &str, view_name: &str) -> DbResult<ViewMetadata> {
+    #[instrument(
+        skip(self, view_name, user_types),
+        name = "introspect_view",
+        level = "trace"
+    )]
+    async fn introspect_view_with_types(
+        &self,
+        schema_name: &str,
+        view_name: &str,
+        user_types: &UserDefinedTypes,
+    ) -> DbResult<ViewMetadata> {
         let columns_query = r#"
             SELECT
                 c.column_name::TEXT,
                 c.data_type::TEXT,
+                c.udt_schema::TEXT,
                 c.udt_name::TEXT,
+                c.domain_name::TEXT,
                 c.is_nullable::TEXT,
                 c.column_default,
-                pg_catalog.col_description((quote_ident(c.table_schema) || '.' || quote_ident(c.table_name))::regclass::oid, c.ordinal_position) AS column_comment,
-                -- Views do not have primary keys, so this is always false.
-                false AS is_primary_key
+                c.collation_name::TEXT,
+                -- View columns rarely carry their own comments, so fall back to the comment on a
+                -- same-named column of a relation the view's rewrite rule depends on.
+                COALESCE(
+                    pg_catalog.col_description((quote_ident(c.table_schema) || '.' || quote_ident(c.table_name))::regclass::oid, c.ordinal_position),
+                    (
+                        SELECT pg_catalog.col_description(d.refobjid, d.refobjsubid)
+                        FROM pg_catalog.pg_rewrite r
+                        JOIN pg_catalog.pg_depend d
+                            ON d.classid = 'pg_catalog.pg_rewrite'::regclass
+                            AND d.objid = r.oid
+                            AND d.refclassid = 'pg_catalog.pg_class'::regclass
+                            AND d.refobjsubid > 0
+                        JOIN pg_catalog.pg_attribute src
+                            ON src.attrelid = d.refobjid AND src.attnum = d.refobjsubid
+                        WHERE r.ev_class = (quote_ident(c.table_schema) || '.' || quote_ident(c.table_name))::regclass
+                          AND d.refobjid <> r.ev_class
+                          AND src.attname = c.column_name
+                          AND pg_catalog.col_description(d.refobjid, d.refobjsubid) IS NOT NULL
+                        ORDER BY d.refobjid
+                        LIMIT 1
+                    )
+                ) AS column_comment,
+                (
+                    SELECT a.attndims::INT4 FROM pg_catalog.pg_attribute a
+                    WHERE a.attrelid = (quote_ident(c.table_schema) || '.' || quote_ident(c.table_name))::regclass AND a.attname = c.column_name
+                ) AS array_dimensions,
+                (
+                    SELECT pg_catalog.format_type(a.atttypid, a.atttypmod) FROM pg_catalog.pg_attribute a
+                    WHERE a.attrelid = (quote_ident(c.table_schema) || '.' || quote_ident(c.table_name))::regclass AND a.attname = c.column_name
+                ) AS canonical_sql_type,
+                -- Views do not have primary keys, so this is always NULL.
+                NULL::INT4 AS primary_key_position,
+                false AS is_identity
             FROM information_schema.columns c
             WHERE c.table_schema = $1 AND c.table_name = $2
             ORDER BY c.ordinal_position;
         "#;
 
         let definition_query = "
-            SELECT view_definition::TEXT FROM information_schema.views 
+            SELECT
+                view_definition::TEXT,
+                (is_updatable = 'YES') AS is_updatable,
+                (is_insertable_into = 'YES') AS is_insertable,
+                pg_catalog.obj_description(
+                    (quote_ident(table_schema) || '.' || quote_ident(table_name))::regclass::oid,
+                    'pg_class'
+                ) AS view_comment
+            FROM information_schema.views
             WHERE table_schema = $1 AND table_name = $2
         ";
 
+        let params = [schema_name, view_name];
         let (columns_result, definition_result) = tokio::join!(
-            sqlx::query_as::<_, ColumnIntrospectionRow>(columns_query)
-                .bind(schema_name)
-                .bind(view_name)
-                .fetch_all(&*self.client.pool),
-            sqlx::query_scalar::<_, Option<String>>(definition_query)
-                .bind(schema_name)
-                .bind(view_name)
-                .fetch_one(&*self.client.pool)
+            self.executor::<ColumnIntrospectionRow>()
+                .fetch_all(columns_query, &params),
+            self.executor::<ViewDefinitionRow>()
+                .fetch_all(definition_query, &params)
         );
 
         let column_rows = columns_result?;
-        let definition = definition_result?;
+        let view_row = definition_result?
+            .into_iter()
+            .next()
+            .ok_or_else(|| DbError::NotFound(format!("View '{}.{}'", schema_name, view_name)))?;
 
         let columns = column_rows
             .into_iter()
-            .map(|row| ColumnMetadata {
-                name: row.column_name,
-                sql_type_name: row.data_type.clone(),
-                axion_type: self
-                    .type_mapper
-                    .sql_to_axion(&row.data_type, Some(&row.udt_name)),
-                is_nullable: row.is_nullable.to_lowercase() == "yes",
-                is_primary_key: false, // Views do not have primary keys
-                default_value: row.column_default,
-                comment: row.column_comment,
-                foreign_key: None, // Views do not have foreign keys
+            .map(|row| {
+                let axion_type = self.map_column_type(schema_name, &row.column_type, user_types);
+                let column_type = row.column_type;
+                ColumnMetadata {
+                    name: row.column_name,
+                    canonical_sql_type: column_type
+                        .canonical_sql_type
+                        .unwrap_or_else(|| column_type.data_type.clone()),
+                    sql_type_name: column_type.data_type,
+                    axion_type,
+                    is_nullable: parse_nullable(&row.is_nullable),
+                    is_primary_key: false, // Views do not have primary keys
+                    is_auto_increment: false,
+                    default_value: row.column_default,
+                    collation: row.collation_name,
+                    comment: row.column_comment,
+                    foreign_key: None, // Views do not have foreign keys
+                    is_system: false,
+                }
             })
-            .collect();
+            .collect::<Vec<_>>();
+
+        let column_names: Vec<&str> = columns.iter().map(|c| c.name.as_str()).collect();
+        let logical_primary_key = self
+            .options
+            .view_primary_key(schema_name, view_name, &column_names)
+            .unwrap_or_else(|e| {
+                warn!("{}", e);
+                Vec::new()
+            });
 
         Ok(ViewMetadata {
             name: view_name.to_string(),
             schema: schema_name.to_string(),
             columns,
-            definition,
-            comment: None, // View comments would require another query
+            definition: view_row.view_definition,
+            comment: view_row.view_comment,
+            is_updatable: view_row.is_updatable,
+            is_insertable: view_row.is_insertable,
+            logical_primary_key,
         })
     }
 
-    #[instrument(skip(self), name = "introspect_schema_enums")]
-    async fn introspect_enums_for_schema(
+    /// Maps a column row to its `AxionDataType`, expanding multi-dimensional arrays
+    /// and wrapping domain columns around the type of their underlying base type.
+    fn map_column_type(
         &self,
         schema_name: &str,
-    ) -> DbResult<HashMap<String, EnumMetadata>> {
-        let query = "
+        row: &ColumnTypeRow,
+        user_types: &UserDefinedTypes,
+    ) -> AxionDataType {
+        let mut base = self.type_mapper_for(schema_name).sql_to_axion(
+            &row.data_type,
+            Some(&row.udt_schema),
+            Some(&row.udt_name),
+            user_types,
+        );
+        // `udt_name` is the same for every dimension (e.g. `_int4` for both `int[]` and
+        // `int[][]`), so nest one extra array level per declared dimension beyond the first.
+        if matches!(base, AxionDataType::Array(_)) {
+            for _ in 1..row.array_dimensions.unwrap_or(1) {
+                base = AxionDataType::Array(Box::new(base));
+            }
+        }
+        if let Some(sql_type) = &row.canonical_sql_type {
+            base = base.with_spatial_modifiers(sql_type);
+        }
+        match &row.domain_name {
+            Some(domain) => AxionDataType::Domain {
+                name: domain.clone(),
+                base: Box::new(base),
+            },
+            None => base,
+        }
+    }
+
+    /// Lists the functions, procedures, aggregates and window functions of a schema,
+    /// leaving out those that belong to an extension. Routines are keyed by name, except
+    /// overloads, which share one: each of them is keyed by its signature instead, such
+    /// as `discount(numeric, integer)`.
+    #[instrument(skip(self, user_types), name = "introspect_routines", level = "debug")]
+    async fn introspect_routines(
+        &self,
+        schema_name: &str,
+        user_types: &UserDefinedTypes,
+    ) -> DbResult<HashMap<String, FunctionMetadata>> {
+        // Before prokind there were no procedures, only the aggregate and window flags.
+        let kind = if self.server_version().await >= PG_PROKIND {
+            "p.prokind::TEXT"
+        } else {
+            "CASE WHEN p.proisagg THEN 'a' WHEN p.proiswindow THEN 'w' ELSE 'f' END"
+        };
+        // The return type is reported the way information_schema reports column types,
+        // so it goes through the same type mapper.
+        let query = format!(
+            "
             SELECT
-                t.typname::TEXT AS enum_name,
-                e.enumlabel::TEXT AS enum_value
-            FROM pg_catalog.pg_type t
-            JOIN pg_catalog.pg_namespace n ON n.oid = t.typnamespace
-            JOIN pg_catalog.pg_enum e ON t.oid = e.enumtypid
-            WHERE n.nspname = $1 AND t.typtype = 'e'
-            ORDER BY enum_name, e.enumsortorder;
+                p.oid::INT8 AS routine_oid,
+                p.proname::TEXT AS routine_name,
+                pg_catalog.oidvectortypes(p.proargtypes) AS argument_types,
+                {kind} AS routine_kind,
+                CASE
+                    WHEN {kind} = 'p' OR t.typname = 'void' THEN NULL
+                    WHEN t.typcategory = 'A' THEN 'ARRAY'
+                    WHEN t.typtype IN ('e', 'c') THEN 'USER-DEFINED'
+                    ELSE pg_catalog.format_type(p.prorettype, NULL)
+                END AS return_data_type,
+                tn.nspname::TEXT AS return_udt_schema,
+                t.typname::TEXT AS return_udt_name,
+                pg_catalog.obj_description(p.oid, 'pg_proc') AS routine_comment
+            FROM pg_catalog.pg_proc p
+            JOIN pg_catalog.pg_namespace n ON n.oid = p.pronamespace
+            JOIN pg_catalog.pg_type t ON t.oid = p.prorettype
+            JOIN pg_catalog.pg_namespace tn ON tn.oid = t.typnamespace
+            WHERE n.nspname = $1
+              AND NOT EXISTS (
+                  SELECT 1 FROM pg_catalog.pg_depend d
+                  WHERE d.classid = 'pg_catalog.pg_proc'::regclass
+                    AND d.objid = p.oid
+                    AND d.deptype = 'e'
+              )
+            ORDER BY p.oid;
+        "
+        );
+        // One row per argument, output and variadic ones included, in declaration order.
+        // `proallargtypes` is only set when some argument is not a plain input.
+        let parameters_query = "
+            SELECT
+                p.oid::INT8 AS routine_oid,
+                COALESCE(p.proargnames[a.position], '')::TEXT AS parameter_name,
+                COALESCE(p.proargmodes[a.position], 'i')::TEXT AS parameter_mode,
+                CASE
+                    WHEN t.typcategory = 'A' THEN 'ARRAY'
+                    WHEN t.typtype IN ('e', 'c') THEN 'USER-DEFINED'
+                    ELSE pg_catalog.format_type(a.type_oid, NULL)
+                END AS data_type,
+                tn.nspname::TEXT AS udt_schema,
+                t.typname::TEXT AS udt_name,
+                pg_catalog.format_type(a.type_oid, NULL) AS sql_type_name,
+                p.pronargdefaults::INT4 AS default_count
+            FROM pg_catalog.pg_proc p
+            JOIN pg_catalog.pg_namespace n ON n.oid = p.pronamespace
+            CROSS JOIN LATERAL unnest(COALESCE(p.proallargtypes, p.proargtypes::OID[]))
+                WITH ORDINALITY AS a(type_oid, position)
+            JOIN pg_catalog.pg_type t ON t.oid = a.type_oid
+            JOIN pg_catalog.pg_namespace tn ON tn.oid = t.typnamespace
+            WHERE n.nspname = $1
+            ORDER BY p.oid, a.position;
         ";
+        let params = [schema_name];
+        let (rows_result, parameters_result) = tokio::join!(
+            self.executor::<RoutineIntrospectionRow>()
+                .fetch_all(&query, &params),
+            self.executor::<ParameterIntrospectionRow>()
+                .fetch_all(parameters_query, &params)
+        );
+        let rows = rows_result?;
+        let mut parameters: HashMap<i64, Vec<ParameterIntrospectionRow>> = HashMap::new();
+        for row in parameters_result? {
+            parameters.entry(row.routine_oid).or_default().push(row);
+        }
 
-        let rows: Vec<EnumIntrospectionRow> = sqlx::query_as(query)
-            .bind(schema_name)
-            .fetch_all(&*self.client.pool)
-            .await?;
+        let mut overloads: HashMap<&str, usize> = HashMap::new();
+        for row in &rows {
+            *overloads.entry(row.routine_name.as_str()).or_default() += 1;
+        }
+        let overloaded: HashSet<String> = overloads
+            .into_iter()
+            .filter(|(_, count)| *count > 1)
+            .map(|(name, _)| name.to_string())
+            .collect();
 
-        let mut enums = HashMap::new();
+        let mapper = self.type_mapper_for(schema_name);
+        let mut routines = HashMap::new();
         for row in rows {
-            enums
-                .entry(row.enum_name.clone())
-                .or_insert_with(|| EnumMetadata {
-                    name: row.enum_name,
+            let kind = match row.routine_kind.as_str() {
+                "f" => RoutineKind::Function,
+                "p" => RoutineKind::Procedure,
+                "a" => RoutineKind::Aggregate,
+                "w" => RoutineKind::Window,
+                other => {
+                    warn!(
+                        "Unknown routine kind '{}' for {}.{}",
+                        other, schema_name, row.routine_name
+                    );
+                    continue;
+                }
+            };
+            let return_type = row.return_data_type.map(|data_type| {
+                mapper.sql_to_axion(
+                    &data_type,
+                    Some(&row.return_udt_schema),
+                    Some(&row.return_udt_name),
+                    user_types,
+                )
+            });
+            let parameters = Self::routine_parameters(
+                mapper,
+                parameters.remove(&row.routine_oid).unwrap_or_default(),
+                user_types,
+            );
+            // Overloads share a name, so each of them is keyed by its signature instead.
+            let key = if overloaded.contains(&row.routine_name) {
+                format!("{}({})", row.routine_name, row.argument_types)
+            } else {
+                row.routine_name.clone()
+            };
+            routines.insert(
+                key,
+                FunctionMetadata {
+                    name: row.routine_name,
                     schema: schema_name.to_string(),
+                    kind: Some(kind),
+                    parameters,
+                    return_type,
+                    comment: row.routine_comment,
                     ..Default::default()
-                })
-                .values
-                .push(row.enum_value);
+                },
+            );
         }
-        Ok(enums)
+        Ok(routines)
     }
 
-    // Add this method inside `impl PostgresIntrospector`
-    #[instrument(skip(self), name = "list_user_schemas")]
-    async fn list_user_schemas(&self) -> DbResult<Vec<String>> {
-        let query = "
-        SELECT nspname::TEXT AS schema_name
-        FROM pg_catalog.pg_namespace
-        WHERE nspname NOT IN ('information_schema', 'pg_catalog', 'pg_toast')
-          AND nspname NOT LIKE 'pg_temp_%'
-        ORDER BY schema_name;
-    ";
-        let rows: Vec<(String,)> = sqlx::query_as(query).fetch_all(&*self.client.pool).await?;
-        Ok(rows.into_iter().map(|r| r.0).collect())
+    /// Maps the argument rows of one routine to its parameters. Columns of a
+    /// `RETURNS TABLE` are left out, and the trailing `default_count` inputs are the ones
+    /// with defaults.
+    fn routine_parameters(
+        mapper: &dyn TypeMapper,
+        rows: Vec<ParameterIntrospectionRow>,
+        user_types: &UserDefinedTypes,
+    ) -> Vec<ParameterMetadata> {
+        let mut parameters: Vec<ParameterMetadata> = rows
+            .iter()
+            .filter_map(|row| {
+                let mode = match row.parameter_mode.as_str() {
+                    "i" => ParameterMode::In,
+                    "o" => ParameterMode::Out,
+                    "b" => ParameterMode::InOut,
+                    "v" => ParameterMode::Variadic,
+                    _ => return None,
+                };
+                Some(ParameterMetadata {
+                    name: row.parameter_name.clone(),
+                    sql_type_name: row.sql_type_name.clone(),
+                    axion_type: mapper.sql_to_axion(
+                        &row.data_type,
+                        Some(&row.udt_schema),
+                        Some(&row.udt_name),
+                        user_types,
+                    ),
+                    mode,
+                    has_default: false,
+                })
+            })
+            .collect();
+
+        let default_count = rows.first().map_or(0, |row| {
+            usize::try_from(row.default_count).unwrap_or_default()
+        });
+        parameters
+            .iter_mut()
+            .rev()
+            .filter(|parameter| parameter.mode != ParameterMode::Out)
+            .take(default_count)
+            .for_each(|parameter| parameter.has_default = true);
+        parameters
+    }
+
+    /// Reads the partitioning and row-level security flags from the table's `pg_class` entry.
+    #[instrument(skip(self), name = "get_relation_info", level = "trace")]
+    async fn get_relation_info(
+        &self,
+        schema_name: &str,
+        table_name: &str,
+    ) -> DbResult<RelationInfoRow> {
+        let version = self.server_version().await;
+        let partitioning = if version >= PG_PARTITIONING {
+            "c.relkind = 'p' AS is_partitioned,
+                CASE WHEN c.relispartition
+                    THEN parent_ns.nspname || '.' || parent.relname
+                END AS partition_of,
+                CASE WHEN c.relispartition
+                    THEN pg_catalog.pg_get_expr(c.relpartbound, c.oid)
+                END AS partition_bound"
+        } else {
+            "false AS is_partitioned,
+                NULL::TEXT AS partition_of,
+                NULL::TEXT AS partition_bound"
+        };
+        let rls_enabled = if version >= PG_ROW_SECURITY {
+            "c.relrowsecurity"
+        } else {
+            "false"
+        };
+        let query = format!(
+            "
+            SELECT
+                {partitioning},
+                {rls_enabled} AS rls_enabled,
+                pg_catalog.obj_description(c.oid, 'pg_class') AS comment
+            FROM pg_catalog.pg_class c
+            JOIN pg_catalog.pg_namespace n ON n.oid = c.relnamespace
+            LEFT JOIN pg_catalog.pg_inherits i ON i.inhrelid = c.oid
+            LEFT JOIN pg_catalog.pg_class parent ON parent.oid = i.inhparent
+            LEFT JOIN pg_catalog.pg_namespace parent_ns ON parent_ns.oid = parent.relnamespace
+            WHERE n.nspname = $1 AND c.relname = $2
+            LIMIT 1;
+        "
+        );
+        let rows: Vec<RelationInfoRow> = self
+            .executor()
+            .fetch_all(&query, &[schema_name, table_name])
+            .await?;
+        Ok(rows.into_iter().next().unwrap_or_default())
+    }
+
+    #[instrument(skip(self), name = "get_policies", level = "trace")]
+    async fn get_policies_for_table(
+        &self,
+        schema_name: &str,
+        table_name: &str,
+    ) -> DbResult<Vec<RlsPolicy>> {
+        if self.server_version().await < PG_ROW_SECURITY {
+            return Ok(Vec::new());
+        }
+        let query = "
+            SELECT
+                policyname::TEXT AS policy_name,
+                cmd::TEXT AS command,
+                array_to_json(roles)::TEXT AS roles,
+                qual::TEXT AS using_expr,
+                with_check::TEXT AS check_expr
+            FROM pg_catalog.pg_policies
+            WHERE schemaname = $1 AND tablename = $2
+            ORDER BY policyname;
+        ";
+        let rows: Vec<PolicyIntrospectionRow> = self
+            .executor()
+            .fetch_all(query, &[schema_name, table_name])
+            .await?;
+        rows.into_iter()
+            .map(|row| {
+                Ok(RlsPolicy {
+                    name: row.policy_name,
+                    table: table_name.to_string(),
+                    command: row.command,
+                    roles: parse_name_list(&row.roles)?,
+                    using_expr: row.using_expr,
+                    check_expr: row.check_expr,
+                })
+            })
+            .collect()
+    }
+
+    #[instrument(skip(self), name = "get_triggers", level = "trace")]
+    async fn get_triggers_for_table(
+        &self,
+        schema_name: &str,
+        table_name: &str,
+    ) -> DbResult<Vec<TriggerMetadata>> {
+        // tgtype is a bitmask: 2 = BEFORE, 64 = INSTEAD OF (otherwise AFTER),
+        // 4 = INSERT, 8 = DELETE, 16 = UPDATE, 32 = TRUNCATE.
+        let query = "
+            SELECT
+                t.tgname::TEXT AS trigger_name,
+                CASE
+                    WHEN t.tgtype::INT4 & 2 <> 0 THEN 'BEFORE'
+                    WHEN t.tgtype::INT4 & 64 <> 0 THEN 'INSTEAD OF'
+                    ELSE 'AFTER'
+                END AS timing,
+                array_to_string(array_remove(ARRAY[
+                    CASE WHEN t.tgtype::INT4 & 4 <> 0 THEN 'INSERT' END,
+                    CASE WHEN t.tgtype::INT4 & 8 <> 0 THEN 'DELETE' END,
+                    CASE WHEN t.tgtype::INT4 & 16 <> 0 THEN 'UPDATE' END,
+                    CASE WHEN t.tgtype::INT4 & 32 <> 0 THEN 'TRUNCATE' END
+                ], NULL), ',') AS events,
+                (pn.nspname || '.' || p.proname)::TEXT AS function_name,
+                pg_catalog.pg_get_triggerdef(t.oid)::TEXT AS definition
+            FROM pg_catalog.pg_trigger t
+            JOIN pg_catalog.pg_class c ON c.oid = t.tgrelid
+            JOIN pg_catalog.pg_namespace n ON n.oid = c.relnamespace
+            JOIN pg_catalog.pg_proc p ON p.oid = t.tgfoid
+            JOIN pg_catalog.pg_namespace pn ON pn.oid = p.pronamespace
+            WHERE NOT t.tgisinternal
+              AND n.nspname = $1
+              AND c.relname = $2
+            ORDER BY t.tgname;
+        ";
+        let rows: Vec<TriggerIntrospectionRow> = self
+            .executor()
+            .fetch_all(query, &[schema_name, table_name])
+            .await?;
+        Ok(rows
+            .into_iter()
+            .map(|row| TriggerMetadata {
+                name: row.trigger_name,
+                table: table_name.to_string(),
+                timing: row.timing,
+                events: row.events.split(',').map(str::to_string).collect(),
+                function: row.function_name,
+                definition: row.definition,
+            })
+            .collect())
+    }
+
+    #[instrument(skip(self), name = "get_indexes_for_table", level = "trace")]
+    async fn get_indexes_for_table(
+        &self,
+        schema_name: &str,
+        table_name: &str,
+    ) -> DbResult<Vec<IndexMetadata>> {
+        // Partial indexes (indpred) and expression indexes (a 0 in indkey) can't serve as
+        // a plain column-set key, so they are skipped. Only the first indnkeyatts entries
+        // of indkey are key columns; the rest are INCLUDE columns. Servers that predate
+        // INCLUDE have no indnkeyatts, and every column is a key column.
+        let key_columns = if self.server_version().await >= PG_PROKIND {
+            "ix.indnkeyatts"
+        } else {
+            "ix.indnatts"
+        };
+        let query = format!(
+            "
+            SELECT
+                i.relname::TEXT AS index_name,
+                array_to_json(ARRAY(
+                    SELECT a.attname
+                    FROM unnest(ix.indkey::INT2[]) WITH ORDINALITY AS k(attnum, ord)
+                    JOIN pg_catalog.pg_attribute a
+                      ON a.attrelid = ix.indrelid AND a.attnum = k.attnum
+                    WHERE k.ord <= {key_columns}
+                    ORDER BY k.ord
+                ))::TEXT AS columns,
+                ix.indisunique AS is_unique,
+                ix.indisprimary AS is_primary
+            FROM pg_catalog.pg_index ix
+            JOIN pg_catalog.pg_class t ON t.oid = ix.indrelid
+            JOIN pg_catalog.pg_class i ON i.oid = ix.indexrelid
+            JOIN pg_catalog.pg_namespace n ON n.oid = t.relnamespace
+            WHERE n.nspname = $1
+              AND t.relname = $2
+              AND ix.indpred IS NULL
+              AND NOT (0 = ANY(ix.indkey::INT2[]))
+            ORDER BY i.relname;
+        "
+        );
+        let rows: Vec<IndexIntrospectionRow> = self
+            .executor()
+            .fetch_all(&query, &[schema_name, table_name])
+            .await?;
+        rows.into_iter()
+            .map(|row| {
+                Ok(IndexMetadata {
+                    name: row.index_name,
+                    columns: parse_name_list(&row.columns)?,
+                    is_unique: row.is_unique,
+                    is_primary: row.is_primary,
+                })
+            })
+            .collect()
+    }
+
+    /// Lists the hidden system columns of a table (those with a negative `attnum`),
+    /// starting with `ctid`.
+    #[instrument(skip(self, user_types), name = "get_system_columns", level = "trace")]
+    async fn get_system_columns(
+        &self,
+        schema_name: &str,
+        table_name: &str,
+        user_types: &UserDefinedTypes,
+    ) -> DbResult<Vec<ColumnMetadata>> {
+        let query = "
+            SELECT
+                a.attname::TEXT AS column_name,
+                t.typname::TEXT AS type_name,
+                pg_catalog.format_type(a.atttypid, a.atttypmod) AS canonical_sql_type
+            FROM pg_catalog.pg_attribute a
+            JOIN pg_catalog.pg_class c ON c.oid = a.attrelid
+            JOIN pg_catalog.pg_namespace n ON n.oid = c.relnamespace
+            JOIN pg_catalog.pg_type t ON t.oid = a.atttypid
+            WHERE n.nspname = $1 AND c.relname = $2 AND a.attnum < 0
+            ORDER BY a.attnum DESC;
+        ";
+        let rows: Vec<SystemColumnRow> = self
+            .executor()
+            .fetch_all(query, &[schema_name, table_name])
+            .await?;
+        let mapper = self.type_mapper_for(schema_name);
+        Ok(rows
+            .into_iter()
+            .map(|row| ColumnMetadata {
+                axion_type: mapper.sql_to_axion(
+                    &row.type_name,
+                    None,
+                    Some(&row.type_name),
+                    user_types,
+                ),
+                name: row.column_name,
+                sql_type_name: row.type_name,
+                canonical_sql_type: row.canonical_sql_type,
+                is_nullable: false,
+                is_primary_key: false,
+                is_auto_increment: false,
+                default_value: None,
+                collation: None,
+                comment: None,
+                foreign_key: None,
+                is_system: true,
+            })
+            .collect())
+    }
+
+    /// Reads the tables of a schema for `introspect_graph`: one query for every column
+    /// and one for every foreign key, straight from the system catalogs.
+    #[instrument(skip(self), name = "introspect_schema_graph", level = "debug")]
+    async fn introspect_schema_graph(&self, schema_name: &str) -> DbResult<SchemaMetadata> {
+        let columns_query = "
+            SELECT
+                c.relname::TEXT AS table_name,
+                a.attname::TEXT AS column_name,
+                CASE
+                    WHEN et.typcategory = 'A' THEN 'ARRAY'
+                    WHEN et.typtype IN ('e', 'c') THEN 'USER-DEFINED'
+                    ELSE pg_catalog.format_type(et.oid, NULL)
+                END AS data_type,
+                pg_catalog.format_type(a.atttypid, a.atttypmod) AS canonical_sql_type,
+                etn.nspname::TEXT AS udt_schema,
+                et.typname::TEXT AS udt_name,
+                CASE WHEN t.typtype = 'd' THEN t.typname::TEXT END AS domain_name,
+                a.attndims::INT4 AS array_dimensions,
+                NOT a.attnotnull AS is_nullable,
+                (
+                    SELECT k.position::INT4
+                    FROM unnest(pk.conkey) WITH ORDINALITY AS k(attnum, position)
+                    WHERE k.attnum = a.attnum
+                ) AS primary_key_position
+            FROM pg_catalog.pg_class c
+            JOIN pg_catalog.pg_namespace n ON n.oid = c.relnamespace
+            JOIN pg_catalog.pg_attribute a
+              ON a.attrelid = c.oid AND a.attnum > 0 AND NOT a.attisdropped
+            JOIN pg_catalog.pg_type t ON t.oid = a.atttypid
+            -- Domain columns are mapped by their base type, like information_schema does.
+            JOIN pg_catalog.pg_type et
+              ON et.oid = CASE WHEN t.typtype = 'd' THEN t.typbasetype ELSE t.oid END
+            JOIN pg_catalog.pg_namespace etn ON etn.oid = et.typnamespace
+            LEFT JOIN pg_catalog.pg_constraint pk
+              ON pk.conrelid = c.oid AND pk.contype = 'p'
+            WHERE n.nspname = $1 AND c.relkind IN ('r', 'p')
+            ORDER BY c.relname, a.attnum;
+        ";
+        // conkey and confkey list the constraint's columns pairwise.
+        let foreign_keys_query = "
+            SELECT
+                c.relname::TEXT AS table_name,
+                a.attname::TEXT AS column_name,
+                fn.nspname::TEXT AS foreign_table_schema,
+                fc.relname::TEXT AS foreign_table_name,
+                fa.attname::TEXT AS foreign_column_name,
+                con.confdeltype::TEXT AS on_delete,
+                con.confupdtype::TEXT AS on_update
+            FROM pg_catalog.pg_constraint con
+            JOIN pg_catalog.pg_class c ON c.oid = con.conrelid
+            JOIN pg_catalog.pg_namespace n ON n.oid = c.relnamespace
+            JOIN pg_catalog.pg_class fc ON fc.oid = con.confrelid
+            JOIN pg_catalog.pg_namespace fn ON fn.oid = fc.relnamespace
+            CROSS JOIN LATERAL unnest(con.conkey, con.confkey) AS k(attnum, foreign_attnum)
+            JOIN pg_catalog.pg_attribute a
+              ON a.attrelid = con.conrelid AND a.attnum = k.attnum
+            JOIN pg_catalog.pg_attribute fa
+              ON fa.attrelid = con.confrelid AND fa.attnum = k.foreign_attnum
+            WHERE n.nspname = $1 AND con.contype = 'f';
+        ";
+        let params = [schema_name];
+        let (column_rows, foreign_key_rows, user_types): (
+            Vec<GraphColumnRow>,
+            Vec<GraphForeignKeyRow>,
+            UserDefinedTypes,
+        ) = tokio::try_join!(
+            self.executor().fetch_all(columns_query, &params),
+            self.executor().fetch_all(foreign_keys_query, &params),
+            self.list_user_types()
+        )?;
+
+        let mut foreign_keys: HashMap<(String, String), ForeignKeyReference> = foreign_key_rows
+            .into_iter()
+            .map(|row| {
+                let reference = ForeignKeyReference {
+                    schema: row.foreign_table_schema,
+                    table: row.foreign_table_name,
+                    column: row.foreign_column_name,
+                    on_delete: referential_action(&row.on_delete),
+                    on_update: referential_action(&row.on_update),
+                };
+                ((row.table_name, row.column_name), reference)
+            })
+            .collect();
+
+        let mut tables: HashMap<String, TableMetadata> = HashMap::new();
+        let mut primary_keys: HashMap<String, Vec<(i32, String)>> = HashMap::new();
+        for row in column_rows {
+            let table = tables
+                .entry(row.table_name.clone())
+                .or_insert_with(|| TableMetadata {
+                    name: row.table_name.clone(),
+                    schema: schema_name.to_string(),
+                    ..Default::default()
+                });
+            if let Some(position) = row.primary_key_position {
+                primary_keys
+                    .entry(row.table_name.clone())
+                    .or_default()
+                    .push((position, row.column_name.clone()));
+            }
+            let foreign_key = foreign_keys.remove(&(row.table_name, row.column_name.clone()));
+            let axion_type = self.map_column_type(schema_name, &row.column_type, &user_types);
+            let column_type = row.column_type;
+            table.columns.push(ColumnMetadata {
+                axion_type,
+                name: row.column_name,
+                canonical_sql_type: column_type
+                    .canonical_sql_type
+                    .unwrap_or_else(|| column_type.data_type.clone()),
+                sql_type_name: column_type.data_type,
+                is_nullable: row.is_nullable,
+                is_primary_key: row.primary_key_position.is_some(),
+                is_auto_increment: false,
+                default_value: None,
+                collation: None,
+                comment: None,
+                foreign_key,
+                is_system: false,
+            });
+        }
+        for (table_name, primary_key) in primary_keys {
+            if let Some(table) = tables.get_mut(&table_name) {
+                table.primary_key_columns = in_key_order(primary_key);
+            }
+        }
+
+        Ok(SchemaMetadata {
+            name: schema_name.to_string(),
+            tables,
+            ..Default::default()
+        })
+    }
+
+    #[instrument(skip(self), name = "get_table_statistics", level = "trace")]
+    async fn get_table_statistics(
+        &self,
+        schema_name: &str,
+        table_name: &str,
+    ) -> DbResult<TableStatistics> {
+        let query = "
+            SELECT
+                c.reltuples::BIGINT AS estimated_rows,
+                pg_catalog.pg_total_relation_size(c.oid)::BIGINT AS total_bytes,
+                pg_catalog.pg_indexes_size(c.oid)::BIGINT AS index_bytes
+            FROM pg_catalog.pg_class c
+            JOIN pg_catalog.pg_namespace n ON n.oid = c.relnamespace
+            WHERE n.nspname = $1 AND c.relname = $2;
+        ";
+        let row: TableStatisticsRow = self
+            .executor()
+            .fetch_one(query, &[schema_name, table_name])
+            .await?;
+        Ok(TableStatistics {
+            estimated_rows: row.estimated_rows,
+            total_bytes: row.total_bytes,
+            index_bytes: row.index_bytes,
+        })
+    }
+
+    /// One pass over a schema. When `fail_on_catalog_change` is set, an entity that vanished
+    /// or changed after being listed aborts the pass with `CatalogChanged` so it can be retried;
+    /// otherwise it is skipped with a warning like any other failure.
+    async fn introspect_schema_attempt(
+        &self,
+        schema_name: &str,
+        progress: ProgressCallback<'_>,
+        fail_on_catalog_change: bool,
+    ) -> DbResult<SchemaMetadata> {
+        let mut schema_meta = SchemaMetadata {
+            name: schema_name.to_string(),
+            ..Default::default()
+        };
+
+        // Fetch all entities, enums and composite types for the schema concurrently, along
+        // with the names of every user-defined type in the database (columns may use types
+        // from other schemas)
+        let (entities_result, enums_result, user_types_result, composites_result) = tokio::join!(
+            self.with_timeout(
+                schema_name,
+                "tables",
+                self.list_tables_and_views(schema_name)
+            ),
+            async {
+                let start = Instant::now();
+                let result = self
+                    .with_timeout(
+                        schema_name,
+                        "enums",
+                        self.introspect_enums_for_schema(schema_name),
+                    )
+                    .await;
+                debug!(schema = %schema_name, elapsed = ?start.elapsed(), "Introspected enums");
+                result
+            },
+            self.with_timeout(schema_name, "user types", self.list_user_types()),
+            self.with_timeout(
+                schema_name,
+                "composite types",
+                self.list_composite_fields(schema_name)
+            )
+        );
+
+        schema_meta.enums = enums_result?;
+
+        let entities = entities_result?;
+        let user_types = user_types_result?;
+        schema_meta.composite_types =
+            self.group_composite_rows(schema_name, composites_result?, &user_types);
+        schema_meta.functions = self
+            .with_timeout(
+                schema_name,
+                "routines",
+                self.introspect_routines(schema_name, &user_types),
+            )
+            .await?;
+
+        let tables_start = Instant::now();
+        for entity in &entities {
+            if entity.table_type == "BASE TABLE" {
+                progress(ProgressEvent::TableStarted {
+                    schema: schema_name.to_string(),
+                    table: entity.table_name.clone(),
+                });
+                let success = match self
+                    .with_timeout(
+                        schema_name,
+                        &entity.table_name,
+                        self.introspect_table_with_types(
+                            schema_name,
+                            &entity.table_name,
+                            &user_types,
+                        ),
+                    )
+                    .await
+                {
+                    Ok(table_md) => {
+                        schema_meta
+                            .tables
+                            .insert(entity.table_name.clone(), table_md);
+                        true
+                    }
+                    Err(e) if fail_on_catalog_change && is_catalog_change(&e) => {
+                        return Err(DbError::CatalogChanged(format!(
+                            "table {}.{}: {}",
+                            schema_name, entity.table_name, e
+                        )));
+                    }
+                    Err(e) => {
+                        self.log_skip(format_args!(
+                            "Skipping table {}.{}: {}",
+                            schema_name, entity.table_name, e
+                        ));
+                        progress(ProgressEvent::Skipped(SkippedEntity {
+                            schema: schema_name.to_string(),
+                            name: Some(entity.table_name.clone()),
+                            reason: e.to_string(),
+                        }));
+                        false
+                    }
+                };
+                progress(ProgressEvent::TableFinished {
+                    schema: schema_name.to_string(),
+                    table: entity.table_name.clone(),
+                    success,
+                });
+            }
+        }
+        debug!(schema = %schema_name, elapsed = ?tables_start.elapsed(), "Introspected tables");
+
+        let views_start = Instant::now();
+        for entity in &entities {
+            if entity.table_type == "VIEW" {
+                progress(ProgressEvent::TableStarted {
+                    schema: schema_name.to_string(),
+                    table: entity.table_name.clone(),
+                });
+                let success = match self
+                    .with_timeout(
+                        schema_name,
+                        &entity.table_name,
+                        self.introspect_view_with_types(
+                            schema_name,
+                            &entity.table_name,
+                            &user_types,
+                        ),
+                    )
+                    .await
+                {
+                    Ok(view_md) => {
+                        schema_meta.views.insert(entity.table_name.clone(), view_md);
+                        true
+                    }
+                    Err(e) if fail_on_catalog_change && is_catalog_change(&e) => {
+                        return Err(DbError::CatalogChanged(format!(
+                            "view {}.{}: {}",
+                            schema_name, entity.table_name, e
+                        )));
+                    }
+                    Err(e) => {
+                        self.log_skip(format_args!(
+                            "Skipping view {}.{}: {}",
+                            schema_name, entity.table_name, e
+                        ));
+                        progress(ProgressEvent::Skipped(SkippedEntity {
+                            schema: schema_name.to_string(),
+                            name: Some(entity.table_name.clone()),
+                            reason: e.to_string(),
+                        }));
+                        false
+                    }
+                };
+                progress(ProgressEvent::TableFinished {
+                    schema: schema_name.to_string(),
+                    table: entity.table_name.clone(),
+                    success,
+                });
+            }
+        }
+        debug!(schema = %schema_name, elapsed = ?views_start.elapsed(), "Introspected views");
+
+        Ok(schema_meta)
+    }
+}
+
+// =================================================================================
+//  3. The Main Introspector Trait Implementation (Now with View/Enum Logic)
+// =================================================================================
+
+#[async_trait::async_trait]
+impl Introspector for PostgresIntrospector {
+    #[instrument(skip(self), name = "introspect_database")]
+    async fn introspect(&self, schemas: &[String]) -> DbResult<DatabaseMetadata> {
+        info!(
+            "Starting full database introspection for schemas: {:?}",
+            schemas
+        );
+        let (db_meta, report) = self.introspect_with_report(schemas).await?;
+        info!(
+            elapsed = ?report.total_duration,
+            "Database introspection complete."
+        );
+        Ok(db_meta)
+    }
+
+    fn includes_referenced_schemas(&self) -> bool {
+        self.options.include_referenced_schemas
+    }
+
+    async fn introspect_schema(&self, schema_name: &str) -> DbResult<SchemaMetadata> {
+        self.introspect_schema_with_progress(schema_name, &|_| {})
+            .await
+    }
+
+    #[instrument(skip(self, progress), name = "introspect_schema")]
+    async fn introspect_schema_with_progress(
+        &self,
+        schema_name: &str,
+        progress: ProgressCallback<'_>,
+    ) -> DbResult<SchemaMetadata> {
+        // A retry visits the tables of earlier attempts again; report each of them once.
+        let reported = Mutex::new(HashSet::new());
+        let progress = &|event: ProgressEvent| {
+            let key = match &event {
+                ProgressEvent::TableStarted { table, .. } => Some((table.clone(), false)),
+                ProgressEvent::TableFinished { table, .. } => Some((table.clone(), true)),
+                _ => None,
+            };
+            let first_time = key.is_none_or(|key| {
+                reported
+                    .lock()
+                    .unwrap_or_else(PoisonError::into_inner)
+                    .insert(key)
+            });
+            if first_time {
+                progress(event);
+            }
+        };
+
+        let retries = self.options.catalog_change_retries;
+        let mut attempt = 0;
+        loop {
+            match self
+                .introspect_schema_attempt(schema_name, progress, attempt < retries)
+                .await
+            {
+                Err(DbError::CatalogChanged(reason)) if attempt < retries => {
+                    attempt += 1;
+                    warn!(
+                        "Catalog of schema '{}' changed during introspection ({}), retrying ({}/{})",
+                        schema_name, reason, attempt, retries
+                    );
+                    // Give the DDL that changed the catalog a moment to finish.
+                    tokio::time::sleep(CATALOG_CHANGE_BACKOFF * attempt).await;
+                }
+                result => return result,
+            }
+        }
+    }
+
+    async fn introspect_table(
+        &self,
+        schema_name: &str,
+        table_name: &str,
+    ) -> DbResult<TableMetadata> {
+        let user_types = self.list_user_types().await?;
+        self.with_timeout(
+            schema_name,
+            table_name,
+            self.introspect_table_with_types(schema_name, table_name, &user_types),
+        )
+        .await
+    }
+
+    // Looks the user-defined types up once for the whole batch instead of once per table.
+    async fn introspect_many(&self, entities: &[(String, String)]) -> DbResult<Vec<TableMetadata>> {
+        let user_types = self.list_user_types().await?;
+        let futures: Vec<_> = entities
+            .iter()
+            .map(|(schema_name, table_name)| {
+                self.with_timeout(
+                    schema_name,
+                    table_name,
+                    self.introspect_table_with_types(schema_name, table_name, &user_types),
+                )
+            })
+            .collect();
+        stream::iter(futures)
+            .buffered(MAX_CONCURRENT_INTROSPECTIONS)
+            .try_collect()
+            .await
+    }
+
+    // =================================== NEW METHODS ===================================
+
+    async fn introspect_view(&self, schema_name: &str, view_name: &str) -> DbResult<ViewMetadata> {
+        let user_types = self.list_user_types().await?;
+        self.with_timeout(
+            schema_name,
+            view_name,
+            self.introspect_view_with_types(schema_name, view_name, &user_types),
+        )
+        .await
+    }
+
+    #[instrument(skip(self), name = "introspect_schema_enums", level = "debug")]
+    async fn introspect_enums_for_schema(
+        &self,
+        schema_name: &str,
+    ) -> DbResult<HashMap<String, EnumMetadata>> {
+        let query = "
+            SELECT
+                t.typname::TEXT AS enum_name,
+                e.enumlabel::TEXT AS enum_value,
+                e.enumsortorder::FLOAT8 AS sort_order,
+                pg_catalog.obj_description(t.oid, 'pg_type') AS enum_comment
+            FROM pg_catalog.pg_type t
+            JOIN pg_catalog.pg_namespace n ON n.oid = t.typnamespace
+            JOIN pg_catalog.pg_enum e ON t.oid = e.enumtypid
+            WHERE n.nspname = $1 AND t.typtype = 'e'
+            ORDER BY enum_name, e.enumsortorder;
+        ";
+
+        let rows: Vec<EnumIntrospectionRow> =
+            self.executor().fetch_all(query, &[schema_name]).await?;
+
+        Ok(Self::group_enum_rows(schema_name, rows))
+    }
+
+    #[instrument(skip(self), name = "introspect_enum", level = "trace")]
+    async fn introspect_enum(&self, schema_name: &str, enum_name: &str) -> DbResult<EnumMetadata> {
+        let query = "
+            SELECT
+                t.typname::TEXT AS enum_name,
+                e.enumlabel::TEXT AS enum_value,
+                e.enumsortorder::FLOAT8 AS sort_order,
+                pg_catalog.obj_description(t.oid, 'pg_type') AS enum_comment
+            FROM pg_catalog.pg_type t
+            JOIN pg_catalog.pg_namespace n ON n.oid = t.typnamespace
+            JOIN pg_catalog.pg_enum e ON t.oid = e.enumtypid
+            WHERE n.nspname = $1 AND t.typname = $2 AND t.typtype = 'e'
+            ORDER BY e.enumsortorder;
+        ";
+
+        let rows: Vec<EnumIntrospectionRow> = self
+            .executor()
+            .fetch_all(query, &[schema_name, enum_name])
+            .await?;
+
+        Self::group_enum_rows(schema_name, rows)
+            .remove(enum_name)
+            .ok_or_else(|| DbError::NotFound(format!("Enum '{}.{}'", schema_name, enum_name)))
+    }
+
+    #[instrument(skip(self), name = "list_relation_names")]
+    async fn list_relation_names(&self, schema_name: &str) -> DbResult<Vec<String>> {
+        Ok(self
+            .list_tables_and_views(schema_name)
+            .await?
+            .into_iter()
+            .filter(|row| row.table_type == "BASE TABLE" || row.table_type == "VIEW")
+            .map(|row| row.table_name)
+            .collect())
+    }
+
+    #[instrument(skip(self), name = "survey")]
+    async fn survey(&self, schemas: &[String]) -> DbResult<SurveyReport> {
+        let privilege_filter = if self.options.accessible_only {
+            "AND has_table_privilege(quote_ident(table_schema) || '.' || quote_ident(table_name), 'SELECT')"
+        } else {
+            ""
+        };
+        let query = format!(
+            "
+            SELECT
+                (SELECT COUNT(*) FROM information_schema.tables
+                 WHERE table_schema = $1 AND table_type = 'BASE TABLE' {privilege_filter}) AS tables,
+                (SELECT COUNT(*) FROM information_schema.tables
+                 WHERE table_schema = $1 AND table_type = 'VIEW' {privilege_filter}) AS views,
+                (SELECT COUNT(*) FROM pg_catalog.pg_type t
+                 JOIN pg_catalog.pg_namespace n ON n.oid = t.typnamespace
+                 WHERE n.nspname = $1 AND t.typtype = 'e') AS enums,
+                (SELECT COUNT(*) FROM pg_catalog.pg_proc p
+                 JOIN pg_catalog.pg_namespace n ON n.oid = p.pronamespace
+                 WHERE n.nspname = $1
+                   AND NOT EXISTS (
+                       SELECT 1 FROM pg_catalog.pg_depend d
+                       WHERE d.classid = 'pg_catalog.pg_proc'::regclass
+                         AND d.objid = p.oid
+                         AND d.deptype = 'e'
+                   )) AS functions;
+        "
+        );
+
+        let mut report = SurveyReport::default();
+        for schema_name in schemas {
+            let row: SurveyRow = self.executor().fetch_one(&query, &[schema_name]).await?;
+            let count = |n: i64| usize::try_from(n).unwrap_or_default();
+            report.per_schema.insert(
+                schema_name.clone(),
+                ModelStats {
+                    schemas: 1,
+                    tables: count(row.tables),
+                    views: count(row.views),
+                    enums: count(row.enums),
+                    functions: count(row.functions),
+                },
+            );
+        }
+        Ok(report)
+    }
+
+    #[instrument(skip(self), name = "introspect_graph")]
+    async fn introspect_graph(&self, schemas: &[String]) -> DbResult<DatabaseMetadata> {
+        let start = Instant::now();
+        let mut db_meta = DatabaseMetadata::default();
+        for schema_name in schemas {
+            let schema_meta = self
+                .with_timeout(
+                    schema_name,
+                    "relationship graph",
+                    self.introspect_schema_graph(schema_name),
+                )
+                .await?;
+            db_meta.schemas.insert(schema_name.clone(), schema_meta);
+        }
+        info!(elapsed = ?start.elapsed(), "Relationship graph introspection complete.");
+        Ok(db_meta)
+    }
+
+    // pg_namespace only ever holds the schemas of the connected database,
+    // so list_user_schemas is already scoped to the current catalog.
+    #[instrument(skip(self), name = "current_catalog")]
+    async fn current_catalog(&self) -> DbResult<String> {
+        let (name,): (String,) = self
+            .executor()
+            .fetch_one("SELECT current_database()::TEXT;", &[])
+            .await?;
+        Ok(name)
+    }
+
+    #[instrument(skip(self), name = "server_info")]
+    async fn server_info(&self) -> DbResult<ServerInfo> {
+        let query = "
+            SELECT
+                current_setting('server_version')::TEXT,
+                current_setting('server_version_num')::INT4,
+                current_setting('server_encoding')::TEXT,
+                current_database()::TEXT;
+        ";
+        let (version, version_num, server_encoding, database_name): (String, i32, String, String) =
+            self.executor().fetch_one(query, &[]).await?;
+        let version_num = u32::try_from(version_num).unwrap_or_default();
+        self.server_version.get_or_init(|| version_num);
+        Ok(ServerInfo {
+            version,
+            version_num,
+            server_encoding,
+            database_name,
+        })
+    }
+
+    #[instrument(skip(self), name = "list_catalogs")]
+    async fn list_catalogs(&self) -> DbResult<Vec<String>> {
+        let query = "
+            SELECT datname::TEXT
+            FROM pg_catalog.pg_database
+            WHERE NOT datistemplate
+              AND datallowconn
+              AND has_database_privilege(datname, 'CONNECT')
+            ORDER BY datname;
+        ";
+        let rows: Vec<(String,)> = self.executor().fetch_all(query, &[]).await?;
+        Ok(rows.into_iter().map(|r| r.0).collect())
+    }
+
+    // Add this method inside `impl PostgresIntrospector`
+    #[instrument(skip(self), name = "list_user_schemas")]
+    async fn list_user_schemas(&self) -> DbResult<Vec<String>> {
+        let privilege_filter = if self.options.accessible_only {
+            "WHERE has_schema_privilege(current_user, nspname, 'USAGE')"
+        } else {
+            ""
+        };
+        // System schemas are filtered out afterwards, by `excluded_schemas`.
+        let query = format!(
+            "
+        SELECT nspname::TEXT AS schema_name
+        FROM pg_catalog.pg_namespace
+        {privilege_filter}
+        ORDER BY schema_name;
+    "
+        );
+        let rows: Vec<(String,)> = self.executor().fetch_all(&query, &[]).await?;
+        Ok(rows
+            .into_iter()
+            .map(|r| r.0)
+            .filter(|name| !self.options.excludes_schema(name))
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{DatabaseType, DbConfig};
+    use async_trait::async_trait;
+    use sqlx::any::AnyPoolOptions;
+
+    /// Answers every query with the same rows, recording the parameters it was given.
+    struct CannedRows<R> {
+        rows: Vec<R>,
+        params: Mutex<Vec<Vec<String>>>,
+    }
+
+    impl<R> CannedRows<R> {
+        fn new(rows: Vec<R>) -> Arc<Self> {
+            Arc::new(Self {
+                rows,
+                params: Mutex::default(),
+            })
+        }
+    }
+
+    #[async_trait]
+    impl<R: Clone + Send + Sync> QueryExecutor<R> for CannedRows<R> {
+        async fn fetch_all(&self, _sql: &str, params: &[&str]) -> DbResult<Vec<R>> {
+            self.params
+                .lock()
+                .unwrap()
+                .push(params.iter().map(ToString::to_string).collect());
+            Ok(self.rows.clone())
+        }
+
+        async fn fetch_one(&self, sql: &str, params: &[&str]) -> DbResult<R> {
+            let rows = self.fetch_all(sql, params).await?;
+            rows.into_iter()
+                .next()
+                .ok_or_else(|| DbError::NotFound("Canned row".to_string()))
+        }
+    }
+
+    /// Fails the test on any query, so that every query a test runs has to be answered
+    /// by canned rows.
+    struct Unanswered;
+
+    #[async_trait]
+    impl<R: Send> QueryExecutor<R> for Unanswered {
+        async fn fetch_all(&self, sql: &str, _params: &[&str]) -> DbResult<Vec<R>> {
+            panic!("No canned rows for the query: {}", sql)
+        }
+
+        async fn fetch_one(&self, sql: &str, _params: &[&str]) -> DbResult<R> {
+            panic!("No canned rows for the query: {}", sql)
+        }
+    }
+
+    /// An introspector for a current server whose pool never connects and whose queries
+    /// all fail until their executors are replaced with canned rows.
+    fn offline_introspector() -> PostgresIntrospector {
+        sqlx::any::install_default_drivers();
+        let pool = Arc::new(
+            AnyPoolOptions::new()
+                .connect_lazy("postgres://localhost/axion_offline")
+                .unwrap(),
+        );
+        let mut introspector = PostgresIntrospector::new(Arc::new(DbClient {
+            pool: pool.clone(),
+            read_pool: pool,
+            config: Arc::new(DbConfig::new(DatabaseType::Postgres)),
+        }));
+        introspector.executors = Executors::new(Arc::new(Unanswered));
+        introspector.server_version.set(u32::MAX).unwrap();
+        introspector
+    }
+
+    fn column_row(
+        name: &str,
+        data_type: &str,
+        udt_name: &str,
+        primary_key_position: Option<i32>,
+    ) -> ColumnIntrospectionRow {
+        ColumnIntrospectionRow {
+            column_name: name.to_string(),
+            column_type: ColumnTypeRow {
+                data_type: data_type.to_string(),
+                canonical_sql_type: None,
+                udt_schema: "app".to_string(),
+                udt_name: udt_name.to_string(),
+                domain_name: None,
+                array_dimensions: None,
+            },
+            is_nullable: "NO".to_string(),
+            column_default: None,
+            collation_name: None,
+            column_comment: None,
+            primary_key_position,
+            is_identity: false,
+        }
+    }
+
+    fn enum_row(name: &str, value: &str, sort_order: f64) -> EnumIntrospectionRow {
+        EnumIntrospectionRow {
+            enum_name: name.to_string(),
+            enum_value: value.to_string(),
+            sort_order,
+            enum_comment: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn groups_enum_rows_from_the_executor() {
+        let rows = CannedRows::new(vec![
+            enum_row("mood", "sad", 1.0),
+            enum_row("mood", "happy", 2.0),
+            enum_row("size", "small", 1.0),
+            enum_row("size", "medium", 1.5),
+            enum_row("size", "large", 2.0),
+        ]);
+        let mut introspector = offline_introspector();
+        introspector.executors.enums = rows.clone();
+
+        let enums = introspector
+            .introspect_enums_for_schema("app")
+            .await
+            .unwrap();
+        assert_eq!(enums["mood"].values, ["sad", "happy"]);
+        assert!(enums["mood"].is_declaration_order);
+        assert_eq!(enums["size"].values, ["small", "medium", "large"]);
+        assert!(!enums["size"].is_declaration_order);
+        assert_eq!(enums["size"].schema, "app");
+        assert_eq!(*rows.params.lock().unwrap(), [["app"]]);
+    }
+
+    #[tokio::test]
+    async fn reports_a_missing_enum_as_not_found() {
+        let mut introspector = offline_introspector();
+        introspector.executors.enums = CannedRows::<EnumIntrospectionRow>::new(Vec::new());
+
+        assert!(matches!(
+            introspector.introspect_enum("app", "mood").await,
+            Err(DbError::NotFound(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn builds_a_table_from_the_executors() {
+        let columns = CannedRows::new(vec![
+            column_row("parcel", "integer", "int4", Some(2)),
+            column_row("order_id", "bigint", "int8", Some(1)),
+            column_row("status", "USER-DEFINED", "order_status", None),
+            column_row("tags", "ARRAY", "_text", None),
+        ]);
+        let mut introspector = offline_introspector();
+        introspector.executors.columns = columns.clone();
+        introspector.executors.foreign_keys = CannedRows::new(vec![ForeignKeyIntrospectionRow {
+            column_name: "order_id".to_string(),
+            foreign_table_schema: "app".to_string(),
+            foreign_table_name: "orders".to_string(),
+            foreign_column_name: "id".to_string(),
+            on_delete: "c".to_string(),
+            on_update: "a".to_string(),
+        }]);
+        introspector.executors.relation_info = CannedRows::new(vec![RelationInfoRow {
+            comment: Some("Parcels of an order".to_string()),
+            ..Default::default()
+        }]);
+        introspector.executors.triggers = CannedRows::new(Vec::new());
+        introspector.executors.indexes = CannedRows::new(Vec::new());
+        introspector.executors.policies = CannedRows::new(Vec::new());
+        let user_types = UserDefinedTypes {
+            enums: HashSet::from([QualifiedName::new("app", "order_status")]),
+            ..Default::default()
+        };
+
+        let table = introspector
+            .introspect_table_with_types("app", "shipments", &user_types)
+            .await
+            .unwrap();
+        assert_eq!(table.primary_key_columns, ["order_id", "parcel"]);
+        assert_eq!(table.comment.as_deref(), Some("Parcels of an order"));
+        let types: Vec<_> = table.columns.iter().map(|c| &c.axion_type).collect();
+        assert_eq!(
+            types,
+            [
+                &AxionDataType::Integer(32),
+                &AxionDataType::Integer(64),
+                &AxionDataType::Enum("order_status".to_string()),
+                &AxionDataType::Array(Box::new(AxionDataType::Text)),
+            ]
+        );
+        let foreign_key = table.columns[1].foreign_key.as_ref().unwrap();
+        assert_eq!(foreign_key.table, "orders");
+        assert_eq!(foreign_key.on_delete, ReferentialAction::Cascade);
+        assert_eq!(*columns.params.lock().unwrap(), [["app", "shipments"]]);
+    }
+
+    #[tokio::test]
+    async fn keys_overloaded_routines_by_signature() {
+        let routine = |oid: i64, argument_types: &str| RoutineIntrospectionRow {
+            routine_oid: oid,
+            routine_name: "discount".to_string(),
+            argument_types: argument_types.to_string(),
+            routine_kind: "f".to_string(),
+            return_data_type: Some("numeric".to_string()),
+            return_udt_schema: "pg_catalog".to_string(),
+            return_udt_name: "numeric".to_string(),
+            routine_comment: None,
+        };
+        let parameter =
+            |oid: i64, name: &str, data_type: &str, udt_name: &str| ParameterIntrospectionRow {
+                routine_oid: oid,
+                parameter_name: name.to_string(),
+                parameter_mode: "i".to_string(),
+                data_type: data_type.to_string(),
+                udt_schema: "pg_catalog".to_string(),
+                udt_name: udt_name.to_string(),
+                sql_type_name: data_type.to_string(),
+                default_count: 1,
+            };
+        let mut introspector = offline_introspector();
+        introspector.executors.routines = CannedRows::new(vec![
+            routine(10, "numeric"),
+            routine(11, "numeric, integer"),
+        ]);
+        introspector.executors.parameters = CannedRows::new(vec![
+            parameter(10, "price", "numeric", "numeric"),
+            parameter(11, "price", "numeric", "numeric"),
+            parameter(11, "percent", "integer", "int4"),
+        ]);
+
+        let routines = introspector
+            .introspect_routines("app", &UserDefinedTypes::default())
+            .await
+            .unwrap();
+        let mut keys: Vec<_> = routines.keys().map(String::as_str).collect();
+        keys.sort();
+        assert_eq!(keys, ["discount(numeric)", "discount(numeric, integer)"]);
+        let two_args = &routines["discount(numeric, integer)"];
+        assert_eq!(two_args.return_type, Some(AxionDataType::Numeric));
+        let defaults: Vec<_> = two_args
+            .parameters
+            .iter()
+            .map(|p| (p.name.as_str(), p.has_default))
+            .collect();
+        assert_eq!(defaults, [("price", false), ("percent", true)]);
     }
 }
@@ -5,12 +5,19 @@
 // These modules contain the internal implementation details.
 // They are `pub` so they can be used by other modules within this crate,
 // but they will NOT be part of the public `prelude`.
+#[cfg(feature = "postgres")]
+pub mod auto_refresh;
+pub mod builder;
 pub mod client;
+pub mod codegen;
 pub mod config;
 pub mod error;
+mod executor;
 pub mod introspection;
 pub mod manager;
 pub mod metadata;
+pub mod query;
+pub mod sql;
 pub mod types;
 
 /// The public-facing prelude for the `axion-db` crate.
@@ -18,24 +25,37 @@ pub mod types;
 /// It exposes the high-level manager and the data structures it returns.
 pub mod prelude {
     // The primary entry point for using this crate.
-    pub use crate::manager::ModelManager;
+    #[cfg(feature = "postgres")]
+    pub use crate::auto_refresh::AutoRefreshHandle;
+    pub use crate::manager::{ModelManager, OutputFormat};
+    pub use crate::query::SelectBuilder;
+
+    // Lower-level access for callers that want to drive introspection themselves.
+    pub use crate::client::{DbClient, PoolStats};
+    pub use crate::introspection::{Introspector, ProgressEvent, new_introspector};
 
     // The configuration struct needed to create a ModelManager.
-    pub use crate::config::{DatabaseType, DbConfig, PoolOptionsConfig};
+    pub use crate::config::{
+        DatabaseType, DbConfig, IntrospectionOptionsConfig, PoolOptionsConfig, TcpOptionsConfig,
+    };
+
+    // The dialect generated SQL is quoted for.
+    pub use crate::sql::Dialect;
+
+    // Naming rules for generated code.
+    pub use crate::codegen::{CodegenOptions, CollisionStrategy};
 
     // The error types that can be returned.
     pub use crate::error::{DbError, DbResult};
 
     // The data structures that describe the database schema.
+    pub use crate::builder::{MetadataBuilder, TableBuilder};
     pub use crate::metadata::{
-        AxionDataType,
-        // We do not export function-related structs yet as they are not implemented.
-        ColumnMetadata,
-        DatabaseMetadata,
-        EnumMetadata,
-        ForeignKeyReference,
-        SchemaMetadata,
-        TableMetadata,
+        AxionDataType, ColumnMetadata, ColumnTypeChange, ColumnWidths, CompositeTypeMetadata,
+        DatabaseMetadata, EnumMetadata, ForeignKeyReference, FunctionMetadata, IndexMetadata,
+        IntrospectionReport, ManyToManyRelationship, ModelStats, QualifiedName, ReferentialAction,
+        Relation, Relationship, RlsPolicy, RoutineKind, SchemaDrift, SchemaMetadata, ServerInfo,
+        SkippedEntity, SurveyReport, TableMetadata, TableStatistics, TriggerMetadata, TypeChange,
         ViewMetadata,
     };
 }
@@ -1,26 +1,44 @@
 // axion-db/src/manager.rs
 use crate::{
-    client::DbClient,
+    client::{DbClient, PoolStats},
     config::DbConfig,
-    error::DbResult,
+    error::{DbError, DbResult},
     introspection::{self, Introspector},
     // IMPORTANT: Make RoutineKind accessible for matching
-    metadata::{DatabaseMetadata, RoutineKind},
+    metadata::{
+        AxionDataType, DatabaseMetadata, IntrospectionReport, ModelStats, RoutineKind, SchemaDrift,
+        SchemaMetadata, TableMetadata,
+    },
+    query::SelectBuilder,
+    sql::Dialect,
 };
 use comfy_table::{presets::UTF8_FULL, Cell, CellAlignment, Table}; // Import comfy-table
+use futures::TryStreamExt;
 use owo_colors::OwoColorize; // Import the colorize trait
-use std::sync::Arc;
-use tracing::info;
+use sqlx::any::{AnyRow, AnyTypeInfoKind};
+use sqlx::{Column, Row, TypeInfo as _};
+use std::collections::{HashMap, HashSet};
+use std::io::Write;
+use std::sync::{Arc, PoisonError, RwLock};
+use tracing::{debug, info, warn};
 
 /// The ModelManager is the primary entry point for database introspection.
 /// It holds the complete database schema and provides methods to interact with it.
 #[derive(Clone)]
 pub struct ModelManager {
     pub db_client: Arc<DbClient>,
-    pub metadata: Arc<DatabaseMetadata>,
+    /// The current metadata snapshot and its introspection report. Swapped in place by
+    /// `refresh` and `refresh_table`, so all clones of the manager observe the update.
+    state: Arc<RwLock<ModelState>>,
     introspector: Arc<dyn Introspector>,
 }
 
+/// What `refresh` replaces in one go, so the report always describes the metadata.
+struct ModelState {
+    metadata: Arc<DatabaseMetadata>,
+    report: Arc<IntrospectionReport>,
+}
+
 impl ModelManager {
     /// Creates a new ModelManager by connecting to the database and performing a full introspection.
     pub async fn new(config: DbConfig) -> DbResult<Self> {
@@ -32,27 +50,464 @@ impl ModelManager {
         let schemas = introspector.list_user_schemas().await?;
 
         info!("Performing full database introspection...");
-        let metadata = introspector.introspect(&schemas).await?;
+        let (metadata, report) = introspector.introspect_with_report(&schemas).await?;
+        let stats = metadata.stats();
         info!(
-            "Introspection complete. Found {} schemas.",
-            metadata.schemas.len()
+            elapsed = ?report.total_duration,
+            skipped = report.skipped.len(),
+            dangling_relationships = report.dangling_relationships.len(),
+            "Introspection complete. Found {} schemas ({} tables, {} views, {} enums).",
+            stats.schemas,
+            stats.tables,
+            stats.views,
+            stats.enums
         );
 
         Ok(Self {
             db_client,
-            metadata: Arc::new(metadata),
+            state: Arc::new(RwLock::new(ModelState {
+                metadata: Arc::new(metadata),
+                report: Arc::new(report),
+            })),
             introspector: Arc::from(introspector),
         })
     }
 
+    /// The dialect that identifiers in generated SQL are quoted for.
+    pub fn dialect(&self) -> Dialect {
+        self.db_client.config.db_type.dialect()
+    }
+
+    /// Returns a snapshot of the current database metadata.
+    /// The snapshot is not affected by later refreshes.
+    pub fn metadata(&self) -> Arc<DatabaseMetadata> {
+        self.metadata_and_report().0
+    }
+
+    /// Returns timing information from the last full introspection, by `new` or `refresh`.
+    pub fn report(&self) -> Arc<IntrospectionReport> {
+        self.metadata_and_report().1
+    }
+
+    /// Returns a metadata snapshot together with the report of the introspection that
+    /// produced it, read at once so a concurrent refresh cannot pair them up wrongly.
+    pub fn metadata_and_report(&self) -> (Arc<DatabaseMetadata>, Arc<IntrospectionReport>) {
+        let state = self.state.read().unwrap_or_else(PoisonError::into_inner);
+        (state.metadata.clone(), state.report.clone())
+    }
+
+    /// Returns a fully owned, deep copy of the current metadata.
+    /// Unlike `metadata()`, the result shares nothing with the manager and can be
+    /// moved to another thread, mutated, or serialized on its own.
+    pub fn snapshot(&self) -> DatabaseMetadata {
+        DatabaseMetadata::clone(&self.metadata())
+    }
+
+    /// Returns the current connection pool counters.
+    pub fn pool_stats(&self) -> PoolStats {
+        self.db_client.pool_stats()
+    }
+
+    /// Returns entity counts for the current metadata.
+    pub fn stats(&self) -> ModelStats {
+        self.metadata().stats()
+    }
+
+    /// Re-introspects every user schema and replaces the cached metadata and report
+    /// wholesale. Existing snapshots keep the metadata they were taken from.
+    pub async fn refresh(&self) -> DbResult<()> {
+        info!("Refreshing database metadata...");
+        let schemas = self.introspector.list_user_schemas().await?;
+        let (metadata, report) = self.introspector.introspect_with_report(&schemas).await?;
+
+        *self.state.write().unwrap_or_else(PoisonError::into_inner) = ModelState {
+            metadata: Arc::new(metadata),
+            report: Arc::new(report),
+        };
+        info!("Metadata refreshed.");
+        Ok(())
+    }
+
+    /// Re-introspects a single table and replaces its entry in the metadata.
+    /// Useful after a migration when only one table is known to have changed.
+    /// The report keeps describing the last full introspection.
+    pub async fn refresh_table(&self, schema: &str, table: &str) -> DbResult<()> {
+        info!("Refreshing table {}.{}...", schema, table);
+        let table_md = self.introspector.introspect_table(schema, table).await?;
+
+        let mut guard = self.state.write().unwrap_or_else(PoisonError::into_inner);
+        // Only clones the metadata if someone still holds an older snapshot.
+        Arc::make_mut(&mut guard.metadata)
+            .schemas
+            .entry(schema.to_string())
+            .or_insert_with(|| SchemaMetadata {
+                name: schema.to_string(),
+                ..Default::default()
+            })
+            .tables
+            .insert(table.to_string(), table_md);
+        Ok(())
+    }
+
+    /// Compares the cached metadata against the live database by name only, reporting
+    /// schemas, tables and views that were dropped or added since the last introspection.
+    /// Much cheaper than a full re-introspection; column-level changes are not detected.
+    pub async fn validate_against_live(&self) -> DbResult<Vec<SchemaDrift>> {
+        let metadata = self.metadata();
+        let live_schemas: HashSet<String> = self
+            .introspector
+            .list_user_schemas()
+            .await?
+            .into_iter()
+            .collect();
+
+        let mut drift = Vec::new();
+        for schema in &live_schemas {
+            if !metadata.schemas.contains_key(schema) {
+                drift.push(SchemaDrift::SchemaAdded(schema.clone()));
+            }
+        }
+        for (schema_name, schema) in &metadata.schemas {
+            if !live_schemas.contains(schema_name) {
+                drift.push(SchemaDrift::SchemaDropped(schema_name.clone()));
+                continue;
+            }
+            let live: HashSet<String> = self
+                .introspector
+                .list_relation_names(schema_name)
+                .await?
+                .into_iter()
+                .collect();
+            for name in schema.tables.keys().chain(schema.views.keys()) {
+                if !live.contains(name) {
+                    drift.push(SchemaDrift::RelationDropped {
+                        schema: schema_name.clone(),
+                        name: name.clone(),
+                    });
+                }
+            }
+            for name in &live {
+                if !schema.tables.contains_key(name) && !schema.views.contains_key(name) {
+                    drift.push(SchemaDrift::RelationAdded {
+                        schema: schema_name.clone(),
+                        name: name.clone(),
+                    });
+                }
+            }
+        }
+
+        drift.sort();
+        if !drift.is_empty() {
+            warn!(
+                "Cached metadata has drifted from the live database ({} changes)",
+                drift.len()
+            );
+        }
+        Ok(drift)
+    }
+
+    // =================================================================================
+    //  Data Helpers
+    // =================================================================================
+
+    /// Returns the number of rows in a table or view.
+    /// The relation is validated against the introspected metadata before any SQL is built.
+    pub async fn count_rows(&self, schema: &str, table: &str) -> DbResult<i64> {
+        let metadata = self.metadata();
+        let schema_data = metadata
+            .schemas
+            .get(schema)
+            .ok_or_else(|| DbError::NotFound(format!("Schema '{}'", schema)))?;
+        if !schema_data.tables.contains_key(table) && !schema_data.views.contains_key(table) {
+            return Err(DbError::NotFound(format!("Table '{}.{}'", schema, table)));
+        }
+
+        let dialect = self.dialect();
+        let sql = format!(
+            "SELECT COUNT(*) FROM {}.{}",
+            dialect.quote_ident(schema),
+            dialect.quote_ident(table)
+        );
+        debug!("Executing: {}", sql);
+        sqlx::query_scalar::<_, i64>(&sql)
+            .fetch_one(&*self.db_client.pool)
+            .await
+            .map_err(|e| DbError::query_execution(e, &self.db_client.pool))
+    }
+
+    /// Checks whether a row with the given primary key exists in a table.
+    /// `pk_values` must be given in the same order as the table's primary key columns.
+    pub async fn row_exists(
+        &self,
+        schema: &str,
+        table: &str,
+        pk_values: &[&str],
+    ) -> DbResult<bool> {
+        let table_data = self.get_table(schema, table)?;
+
+        if table_data.primary_key_columns.is_empty() {
+            return Err(DbError::InvalidInput(format!(
+                "Table '{}.{}' has no primary key",
+                schema, table
+            )));
+        }
+        if table_data.primary_key_columns.len() != pk_values.len() {
+            return Err(DbError::InvalidInput(format!(
+                "Table '{}.{}' has {} primary key column(s), but {} value(s) were given",
+                schema,
+                table,
+                table_data.primary_key_columns.len(),
+                pk_values.len()
+            )));
+        }
+
+        // The values are bound as text and cast to each key column's type, which keeps
+        // the primary key index usable.
+        let dialect = self.dialect();
+        let conditions = table_data
+            .primary_key_columns
+            .iter()
+            .enumerate()
+            .map(|(i, name)| {
+                let sql_type = table_data
+                    .columns
+                    .iter()
+                    .find(|c| &c.name == name)
+                    .map_or("", |c| c.comparison_sql_type());
+                dialect.eq_param(&dialect.quote_ident(name), sql_type, i + 1)
+            })
+            .collect::<Vec<_>>()
+            .join(" AND ");
+        let sql = format!(
+            "SELECT EXISTS (SELECT 1 FROM {}.{} WHERE {})",
+            dialect.quote_ident(schema),
+            dialect.quote_ident(table),
+            conditions
+        );
+        debug!("Executing: {}", sql);
+
+        let mut query = sqlx::query_scalar::<_, bool>(&sql);
+        for value in pk_values {
+            query = query.bind(*value);
+        }
+        query
+            .fetch_one(&*self.db_client.pool)
+            .await
+            .map_err(|e| DbError::query_execution(e, &self.db_client.pool))
+    }
+
+    /// Runs an arbitrary parameterized query and returns each row as a JSON object keyed
+    /// by column name. `params` are bound in order; arrays and objects are bound as JSON text.
+    ///
+    /// Values are decoded by the type sqlx reports for their column. Text columns named
+    /// like a column of the introspected tables are converted according to its
+    /// `AxionDataType`, so `SELECT payload::TEXT AS payload ...` returns `payload` as JSON.
+    /// Names whose columns disagree on the type are left as text.
+    /// The driver only carries booleans, numbers, text and bytes, so cast other Postgres
+    /// types (`uuid`, `numeric`, `timestamptz`, `jsonb`, ...) to `TEXT` in the query.
+    pub async fn query_json(
+        &self,
+        sql: &str,
+        params: &[serde_json::Value],
+    ) -> DbResult<Vec<serde_json::Value>> {
+        use serde_json::Value;
+
+        let mut query = sqlx::query(sql);
+        for param in params {
+            query = match param {
+                Value::Null => query.bind(None::<String>),
+                Value::Bool(b) => query.bind(*b),
+                Value::Number(n) => match n.as_i64() {
+                    Some(i) => query.bind(i),
+                    None => query.bind(n.as_f64()),
+                },
+                Value::String(s) => query.bind(s.clone()),
+                other => query.bind(other.to_string()),
+            };
+        }
+        debug!("Executing: {}", sql);
+        let rows = query
+            .fetch_all(&*self.db_client.pool)
+            .await
+            .map_err(|e| DbError::query_execution(e, &self.db_client.pool))?;
+
+        let metadata = self.metadata();
+        let known_types = known_column_types(&metadata);
+        rows.iter()
+            .map(|row| any_row_to_json(row, &known_types))
+            .collect()
+    }
+
+    /// Streams every row of a table to `writer` as JSON Lines (one object per row).
+    /// Values are converted to JSON according to each column's `AxionDataType`.
+    /// Returns the number of rows written.
+    pub async fn export_table_jsonl<W: Write>(
+        &self,
+        schema: &str,
+        table: &str,
+        mut writer: W,
+    ) -> DbResult<u64> {
+        let table_data = self.get_table(schema, table)?;
+        let sql = select_as_text_sql(&table_data, self.dialect());
+        debug!("Executing: {}", sql);
+
+        let mut rows = sqlx::query(&sql).fetch(&*self.db_client.pool);
+        let mut count = 0;
+        while let Some(row) = rows
+            .try_next()
+            .await
+            .map_err(|e| DbError::query_execution(e, &self.db_client.pool))?
+        {
+            let mut object = serde_json::Map::with_capacity(table_data.columns.len());
+            for (i, col) in table_data.columns.iter().enumerate() {
+                let value: Option<String> = row.try_get(i).map_err(DbError::QueryExecution)?;
+                object.insert(col.name.clone(), text_to_json(value, &col.axion_type));
+            }
+            serde_json::to_writer(&mut writer, &object).map_err(std::io::Error::from)?;
+            writer.write_all(b"\n")?;
+            count += 1;
+        }
+        writer.flush()?;
+        Ok(count)
+    }
+
+    /// Streams every row of a table to `writer` as CSV, preceded by a header row.
+    /// `NULL` values are written as empty fields. Returns the number of rows written.
+    pub async fn export_table_csv<W: Write>(
+        &self,
+        schema: &str,
+        table: &str,
+        mut writer: W,
+    ) -> DbResult<u64> {
+        let table_data = self.get_table(schema, table)?;
+        let sql = select_as_text_sql(&table_data, self.dialect());
+        debug!("Executing: {}", sql);
+
+        let header = table_data
+            .columns
+            .iter()
+            .map(|col| csv_field(&col.name))
+            .collect::<Vec<_>>()
+            .join(",");
+        writeln!(writer, "{}", header)?;
+
+        let mut rows = sqlx::query(&sql).fetch(&*self.db_client.pool);
+        let mut count = 0;
+        while let Some(row) = rows
+            .try_next()
+            .await
+            .map_err(|e| DbError::query_execution(e, &self.db_client.pool))?
+        {
+            let mut fields = Vec::with_capacity(table_data.columns.len());
+            for i in 0..table_data.columns.len() {
+                let value: Option<String> = row.try_get(i).map_err(DbError::QueryExecution)?;
+                fields.push(value.as_deref().map(csv_field).unwrap_or_default());
+            }
+            writeln!(writer, "{}", fields.join(","))?;
+            count += 1;
+        }
+        writer.flush()?;
+        Ok(count)
+    }
+
+    /// Starts a `SELECT` against a table. Column names are checked against the
+    /// introspected metadata when the query runs, before anything is sent to the database.
+    pub fn select(&self, schema: &str, table: &str) -> SelectBuilder<'_> {
+        SelectBuilder::new(self, schema, table)
+    }
+
+    /// Looks up a table in the current metadata snapshot.
+    pub(crate) fn get_table(&self, schema: &str, table: &str) -> DbResult<TableMetadata> {
+        self.metadata()
+            .schemas
+            .get(schema)
+            .and_then(|s| s.tables.get(table))
+            .cloned()
+            .ok_or_else(|| DbError::NotFound(format!("Table '{}.{}'", schema, table)))
+    }
+
+    /// Looks up a schema, falling back to a case-insensitive match when the exact name
+    /// is missing. Fails with `DbError::Ambiguous` if several schemas match ignoring case.
+    pub fn get_schema_ci(&self, schema: &str) -> DbResult<SchemaMetadata> {
+        self.metadata().schema_ci(schema).cloned()
+    }
+
+    /// Looks up a table like `get_schema_ci`, matching both the schema and the table
+    /// name case-insensitively when the exact names are missing.
+    pub fn get_table_ci(&self, schema: &str, table: &str) -> DbResult<TableMetadata> {
+        self.metadata().schema_ci(schema)?.table_ci(table).cloned()
+    }
+
     // =================================================================================
     //  DX: Pretty-Printing Methods (WITH THE NEW `display_summary`)
     // =================================================================================
 
+    /// Writes the current metadata to `writer` in the given format.
+    /// `Pretty` is the colorized summary followed by every table and view, as the
+    /// `display_*` methods print them; `Json` and `Yaml` serialize the full metadata.
+    pub fn render<W: Write>(&self, format: OutputFormat, mut writer: W) -> DbResult<()> {
+        let metadata = self.metadata();
+        match format {
+            OutputFormat::Pretty => {
+                writeln!(writer, "{}", SUMMARY_TITLE.green().bold().underline())?;
+                if let Some(server) = self.server_line() {
+                    writeln!(writer, "{}", server.dimmed())?;
+                }
+                match nothing_found(&metadata, &[], "tables", entity_count) {
+                    Some(note) => writeln!(writer, "{}\n", note.yellow())?,
+                    None => writeln!(writer, "{}\n", self.summary_table())?,
+                }
+
+                let mut schemas: Vec<_> = metadata.schemas.values().collect();
+                schemas.sort_by(|a, b| a.name.cmp(&b.name));
+                for schema in schemas {
+                    let mut tables: Vec<_> = schema.tables.values().collect();
+                    tables.sort_by(|a, b| a.name.cmp(&b.name));
+                    for table in tables {
+                        writeln!(writer, "{}", table)?;
+                    }
+                    let mut views: Vec<_> = schema.views.values().collect();
+                    views.sort_by(|a, b| a.name.cmp(&b.name));
+                    for view in views {
+                        writeln!(writer, "{}", view)?;
+                    }
+                }
+            }
+            OutputFormat::Json => {
+                serde_json::to_writer_pretty(&mut writer, &*metadata)
+                    .map_err(std::io::Error::from)?;
+                writeln!(writer)?;
+            }
+            OutputFormat::Yaml => {
+                serde_yaml::to_writer(&mut writer, &*metadata).map_err(std::io::Error::other)?;
+            }
+        }
+        writer.flush()?;
+        Ok(())
+    }
+
     /// Prints a rich, colorized, table-based summary of the database metadata.
     pub fn display_summary(&self) {
         println!(); // Add a newline for spacing
+        println!("{}", SUMMARY_TITLE.green().bold().underline());
+        if let Some(server) = self.server_line() {
+            println!("{}", server.dimmed());
+        }
+        match nothing_found(&self.metadata(), &[], "tables", entity_count) {
+            Some(note) => println!("{}", note.yellow()),
+            None => println!("{}", self.summary_table()),
+        }
+    }
 
+    /// The server the metadata came from, e.g. `PostgreSQL 16.2 (database 'app', encoding UTF8)`.
+    fn server_line(&self) -> Option<String> {
+        let server_info = self.metadata().server_info.clone()?;
+        Some(format!("{} {}", self.db_client.config.db_type, server_info))
+    }
+
+    /// Builds the per-schema entity counts table shown by `display_summary`.
+    fn summary_table(&self) -> Table {
+        let metadata = self.metadata();
         let mut table = Table::new();
 
         // THE FIX: Use the UTF8_BORDERS_ONLY preset.
@@ -67,6 +522,8 @@ impl ModelManager {
                 Cell::new("Enums").add_attribute(comfy_table::Attribute::Bold),
                 Cell::new("Functions").add_attribute(comfy_table::Attribute::Bold),
                 Cell::new("Procedures").add_attribute(comfy_table::Attribute::Bold),
+                Cell::new("Aggregates").add_attribute(comfy_table::Attribute::Bold),
+                Cell::new("Windows").add_attribute(comfy_table::Attribute::Bold),
                 Cell::new("Triggers").add_attribute(comfy_table::Attribute::Bold),
                 Cell::new("Total").add_attribute(comfy_table::Attribute::Bold),
             ]);
@@ -77,14 +534,16 @@ impl ModelManager {
         let mut total_enums = 0;
         let mut total_functions = 0;
         let mut total_procedures = 0;
+        let mut total_aggregates = 0;
+        let mut total_windows = 0;
         let mut total_triggers = 0;
 
         // --- Sort schemas for consistent output ---
-        let mut schemas: Vec<_> = self.metadata.schemas.keys().collect();
+        let mut schemas: Vec<_> = metadata.schemas.keys().collect();
         schemas.sort();
 
         for schema_name in schemas {
-            if let Some(schema_data) = self.metadata.schemas.get(schema_name) {
+            if let Some(schema_data) = metadata.schemas.get(schema_name) {
                 // --- Per-schema Counts ---
                 let tables_count = schema_data.tables.len();
                 let views_count = schema_data.views.len();
@@ -92,17 +551,21 @@ impl ModelManager {
 
                 let mut functions_count = 0;
                 let mut procedures_count = 0;
-                let mut triggers_count = 0;
+                let mut aggregates_count = 0;
+                let mut windows_count = 0;
                 for func_meta in schema_data.functions.values() {
                     match func_meta.kind {
                         Some(RoutineKind::Function) => functions_count += 1,
                         Some(RoutineKind::Procedure) => procedures_count += 1,
-                        Some(RoutineKind::Trigger) => triggers_count += 1,
+                        Some(RoutineKind::Aggregate) => aggregates_count += 1,
+                        Some(RoutineKind::Window) => windows_count += 1,
                         _ => {}
                     }
                 }
+                // Triggers belong to tables, not to the function map
+                let triggers_count: usize = schema_data.tables.values().map(|t| t.triggers.len()).sum();
 
-                let schema_total = tables_count + views_count + enums_count + functions_count + procedures_count + triggers_count;
+                let schema_total = tables_count + views_count + enums_count + functions_count + procedures_count + aggregates_count + windows_count + triggers_count;
 
                 // --- Add to Grand Totals ---
                 total_tables += tables_count;
@@ -110,6 +573,8 @@ impl ModelManager {
                 total_enums += enums_count;
                 total_functions += functions_count;
                 total_procedures += procedures_count;
+                total_aggregates += aggregates_count;
+                total_windows += windows_count;
                 total_triggers += triggers_count;
 
                 // --- Build and Add the Row ---
@@ -120,6 +585,8 @@ impl ModelManager {
                     Cell::new(enums_count).set_alignment(CellAlignment::Right).fg(comfy_table::Color::Magenta),
                     Cell::new(functions_count).set_alignment(CellAlignment::Right).fg(comfy_table::Color::Red),
                     Cell::new(procedures_count).set_alignment(CellAlignment::Right).fg(comfy_table::Color::Yellow),
+                    Cell::new(aggregates_count).set_alignment(CellAlignment::Right).fg(comfy_table::Color::DarkRed),
+                    Cell::new(windows_count).set_alignment(CellAlignment::Right).fg(comfy_table::Color::DarkMagenta),
                     Cell::new(triggers_count).set_alignment(CellAlignment::Right).fg(comfy_table::Color::DarkYellow),
                     Cell::new(schema_total).set_alignment(CellAlignment::Right).add_attribute(comfy_table::Attribute::Bold),
                 ]);
@@ -127,7 +594,7 @@ impl ModelManager {
         }
 
         // --- Grand Total Calculation ---
-        let grand_total = total_tables + total_views + total_enums + total_functions + total_procedures + total_triggers;
+        let grand_total = total_tables + total_views + total_enums + total_functions + total_procedures + total_aggregates + total_windows + total_triggers;
 
         // --- Add the TOTAL row which will act as the footer ---
         // This row will have the bottom border of the table drawn after it.
@@ -138,30 +605,36 @@ impl ModelManager {
             Cell::new(total_enums).set_alignment(CellAlignment::Right).fg(comfy_table::Color::Magenta).add_attribute(comfy_table::Attribute::Bold),
             Cell::new(total_functions).set_alignment(CellAlignment::Right).fg(comfy_table::Color::Red).add_attribute(comfy_table::Attribute::Bold),
             Cell::new(total_procedures).set_alignment(CellAlignment::Right).fg(comfy_table::Color::Yellow).add_attribute(comfy_table::Attribute::Bold),
+            Cell::new(total_aggregates).set_alignment(CellAlignment::Right).fg(comfy_table::Color::DarkRed).add_attribute(comfy_table::Attribute::Bold),
+            Cell::new(total_windows).set_alignment(CellAlignment::Right).fg(comfy_table::Color::DarkMagenta).add_attribute(comfy_table::Attribute::Bold),
             Cell::new(total_triggers).set_alignment(CellAlignment::Right).fg(comfy_table::Color::DarkYellow).add_attribute(comfy_table::Attribute::Bold),
             Cell::new(grand_total).set_alignment(CellAlignment::Right).add_attribute(comfy_table::Attribute::Bold),
         ]);
 
-        // Print the title and the final table
-        println!("{}", " ModelManager Statistics".green().bold().underline());
-        println!("{table}");
+        table
     }
 
     /// Prints a detailed, prism-py-like breakdown of tables for the specified schemas.
     /// If `schemas` is empty, it displays all schemas.
     pub fn display_tables(&self, schemas: &[&str]) {
+        let metadata = self.metadata();
+        warn_unknown_schemas(&metadata, schemas);
+        if let Some(note) = nothing_found(&metadata, schemas, "tables", |s| s.tables.len()) {
+            println!("{}", note.yellow());
+            return;
+        }
+
         println!("\n{:=<80}", "");
         println!("           TABLES OVERVIEW");
         println!("{:=<80}\n", "");
-
         let schemas_to_display: Box<dyn Iterator<Item = &str>> = if schemas.is_empty() {
-            Box::new(self.metadata.schemas.keys().map(|s| s.as_str()))
+            Box::new(metadata.schemas.keys().map(|s| s.as_str()))
         } else {
             Box::new(schemas.iter().copied())
         };
 
         for schema_name in schemas_to_display {
-            if let Some(schema_data) = self.metadata.schemas.get(schema_name) {
+            if let Some(schema_data) = metadata.schemas.get(schema_name) {
                 for table_data in schema_data.tables.values() {
                     // This now uses the beautiful `Display` implementation we wrote for TableMetadata
                     println!("{}\n", table_data);
@@ -173,18 +646,24 @@ impl ModelManager {
     /// Prints a detailed, prism-py-like breakdown of views for the specified schemas.
     /// If `schemas` is empty, it displays all schemas.
     pub fn display_views(&self, schemas: &[&str]) {
+        let metadata = self.metadata();
+        warn_unknown_schemas(&metadata, schemas);
+        if let Some(note) = nothing_found(&metadata, schemas, "views", |s| s.views.len()) {
+            println!("{}", note.yellow());
+            return;
+        }
+
         println!("\n{:=<80}", "");
         println!("           VIEWS OVERVIEW");
         println!("{:=<80}\n", "");
-
         let schemas_to_display: Box<dyn Iterator<Item = &str>> = if schemas.is_empty() {
-            Box::new(self.metadata.schemas.keys().map(|s| s.as_str()))
+            Box::new(metadata.schemas.keys().map(|s| s.as_str()))
         } else {
             Box::new(schemas.iter().copied())
         };
 
         for schema_name in schemas_to_display {
-            if let Some(schema_data) = self.metadata.schemas.get(schema_name) {
+            if let Some(schema_data) = metadata.schemas.get(schema_name) {
                 for view_data in schema_data.views.values() {
                     // Uses the `Display` implementation for ViewMetadata
                     println!("{}\n", view_data);
@@ -196,18 +675,24 @@ impl ModelManager {
     /// Prints a summary of all enums for the specified schemas with enhanced formatting.
     /// If `schemas` is empty, it displays all schemas.
     pub fn display_enums(&self, schemas: &[&str]) {
+        let metadata = self.metadata();
+        warn_unknown_schemas(&metadata, schemas);
+        if let Some(note) = nothing_found(&metadata, schemas, "enums", |s| s.enums.len()) {
+            println!("{}", note.yellow());
+            return;
+        }
+
         println!("\n{:=<80}", "");
         println!("           ENUMS OVERVIEW");
         println!("{:=<80}\n", "");
-
         let schemas_to_display: Box<dyn Iterator<Item = &str>> = if schemas.is_empty() {
-            Box::new(self.metadata.schemas.keys().map(|s| s.as_str()))
+            Box::new(metadata.schemas.keys().map(|s| s.as_str()))
         } else {
             Box::new(schemas.iter().copied())
         };
 
         for schema_name in schemas_to_display {
-            if let Some(schema_data) = self.metadata.schemas.get(schema_name) {
+            if let Some(schema_data) = metadata.schemas.get(schema_name) {
                 if !schema_data.enums.is_empty() {
                     println!("Schema '{}':", schema_name.cyan().bold());
                     for enum_data in schema_data.enums.values() {
@@ -225,4 +710,265 @@ impl ModelManager {
             }
         }
     }
-}
\ No newline at end of file
+}
+
+const SUMMARY_TITLE: &str = " ModelManager Statistics";
+
+/// The formats `ModelManager::render` can produce.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum OutputFormat {
+    /// Colorized tables for a terminal.
+    #[default]
+    Pretty,
+    Json,
+    Yaml,
+}
+
+/// Logs a warning for requested schema names that are not part of the introspected metadata,
+/// so a typo in a filter doesn't silently produce empty output.
+fn warn_unknown_schemas(metadata: &DatabaseMetadata, schemas: &[&str]) {
+    for schema_name in schemas {
+        if !metadata.schemas.contains_key(*schema_name) {
+            warn!(
+                "Schema '{}' not found in the introspected metadata",
+                schema_name
+            );
+        }
+    }
+}
+
+/// The note printed instead of an empty overview, e.g. `No user tables found in schema(s)
+/// public`, or `None` when the selected schemas (every schema when `schemas` is empty)
+/// hold at least one entity counted by `count`.
+fn nothing_found(
+    metadata: &DatabaseMetadata,
+    schemas: &[&str],
+    entities: &str,
+    count: impl Fn(&SchemaMetadata) -> usize,
+) -> Option<String> {
+    let mut names: Vec<&str> = if schemas.is_empty() {
+        metadata.schemas.keys().map(String::as_str).collect()
+    } else {
+        schemas.to_vec()
+    };
+    if names.is_empty() {
+        return Some("No user schemas found".to_string());
+    }
+    let total: usize = names
+        .iter()
+        .filter_map(|name| metadata.schemas.get(*name))
+        .map(count)
+        .sum();
+    if total > 0 {
+        return None;
+    }
+    names.sort_unstable();
+    Some(format!(
+        "No user {} found in schema(s) {}",
+        entities,
+        names.join(", ")
+    ))
+}
+
+/// Every entity the summary counts, so a schema holding only views or functions
+/// still gets a summary.
+fn entity_count(schema: &SchemaMetadata) -> usize {
+    schema.tables.len() + schema.views.len() + schema.enums.len() + schema.functions.len()
+}
+
+/// Builds a `SELECT` that returns every column of a table cast to text,
+/// in the order of `TableMetadata::columns`.
+fn select_as_text_sql(table: &TableMetadata, dialect: Dialect) -> String {
+    let columns = table
+        .columns
+        .iter()
+        .map(|col| {
+            let name = dialect.quote_ident(&col.name);
+            format!("{} AS {}", dialect.cast_to_text(&name), name)
+        })
+        .collect::<Vec<_>>()
+        .join(", ");
+    format!(
+        "SELECT {} FROM {}",
+        columns,
+        table.qualified_name().quoted_in(dialect)
+    )
+}
+
+/// Converts a column's text representation into the closest JSON value for its type.
+pub(crate) fn text_to_json(value: Option<String>, axion_type: &AxionDataType) -> serde_json::Value {
+    use serde_json::Value;
+    let Some(text) = value else {
+        return Value::Null;
+    };
+    match axion_type {
+        AxionDataType::Integer(_) => text
+            .parse::<i64>()
+            .map(Value::from)
+            .unwrap_or(Value::String(text)),
+        AxionDataType::Float(_) => text
+            .parse::<f64>()
+            .ok()
+            .and_then(serde_json::Number::from_f64)
+            .map(Value::Number)
+            .unwrap_or(Value::String(text)),
+        AxionDataType::Boolean => Value::Bool(matches!(text.as_str(), "t" | "true")),
+        AxionDataType::Json | AxionDataType::JsonB => {
+            serde_json::from_str(&text).unwrap_or(Value::String(text))
+        }
+        AxionDataType::Domain { base, .. } => text_to_json(Some(text), base),
+        // NUMERIC stays a string so no precision is lost.
+        _ => Value::String(text),
+    }
+}
+
+/// Maps every column name used by the introspected tables to its type, leaving out
+/// names whose columns disagree on the type.
+fn known_column_types(metadata: &DatabaseMetadata) -> HashMap<&str, &AxionDataType> {
+    let mut types: HashMap<&str, Option<&AxionDataType>> = HashMap::new();
+    for col in metadata.all_tables().flat_map(|table| &table.columns) {
+        types
+            .entry(col.name.as_str())
+            .and_modify(|known| {
+                if *known != Some(&col.axion_type) {
+                    *known = None;
+                }
+            })
+            .or_insert(Some(&col.axion_type));
+    }
+    types
+        .into_iter()
+        .filter_map(|(name, axion_type)| Some((name, axion_type?)))
+        .collect()
+}
+
+/// Converts a row of an arbitrary query into a JSON object; see `ModelManager::query_json`.
+/// Bytes are written the way Postgres prints `bytea`, e.g. `\x0a1b`.
+fn any_row_to_json(
+    row: &AnyRow,
+    known_types: &HashMap<&str, &AxionDataType>,
+) -> DbResult<serde_json::Value> {
+    use serde_json::Value;
+
+    let mut object = serde_json::Map::with_capacity(row.len());
+    for column in row.columns() {
+        let i = column.ordinal();
+        let value = match column.type_info().kind() {
+            AnyTypeInfoKind::Null => Value::Null,
+            AnyTypeInfoKind::Bool => {
+                let value: Option<bool> = row.try_get(i).map_err(DbError::QueryExecution)?;
+                value.map_or(Value::Null, Value::Bool)
+            }
+            AnyTypeInfoKind::SmallInt | AnyTypeInfoKind::Integer | AnyTypeInfoKind::BigInt => {
+                let value: Option<i64> = row.try_get(i).map_err(DbError::QueryExecution)?;
+                value.map_or(Value::Null, Value::from)
+            }
+            AnyTypeInfoKind::Real | AnyTypeInfoKind::Double => {
+                let value: Option<f64> = row.try_get(i).map_err(DbError::QueryExecution)?;
+                value.map_or(Value::Null, Value::from)
+            }
+            AnyTypeInfoKind::Text => {
+                let value: Option<String> = row.try_get(i).map_err(DbError::QueryExecution)?;
+                match known_types.get(column.name()) {
+                    Some(axion_type) => text_to_json(value, axion_type),
+                    None => value.map_or(Value::Null, Value::String),
+                }
+            }
+            AnyTypeInfoKind::Blob => {
+                let value: Option<Vec<u8>> = row.try_get(i).map_err(DbError::QueryExecution)?;
+                value.map_or(Value::Null, |bytes| {
+                    let hex: String = bytes.iter().map(|b| format!("{:02x}", b)).collect();
+                    Value::String(format!("\\x{}", hex))
+                })
+            }
+        };
+        object.insert(column.name().to_string(), value);
+    }
+    Ok(Value::Object(object))
+}
+
+/// Quotes a CSV field if it contains a delimiter, quote or line break.
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::builder::MetadataBuilder;
+
+    #[test]
+    fn known_column_types_skip_names_with_conflicting_types() {
+        let metadata = MetadataBuilder::new()
+            .table("events", |t| {
+                t.column("id", AxionDataType::Integer(64))
+                    .column("payload", AxionDataType::JsonB)
+            })
+            .table("users", |t| {
+                t.column("id", AxionDataType::Uuid)
+                    .column("payload", AxionDataType::JsonB)
+            })
+            .build();
+
+        let types = known_column_types(&metadata);
+        assert_eq!(types.get("payload"), Some(&&AxionDataType::JsonB));
+        assert_eq!(types.get("id"), None);
+    }
+
+    #[test]
+    fn nothing_found_names_the_empty_schemas() {
+        let mut metadata = MetadataBuilder::new()
+            .schema("sales")
+            .table("orders", |t| t.column("id", AxionDataType::Integer(64)))
+            .build();
+        metadata.schemas.insert(
+            "audit".to_string(),
+            SchemaMetadata {
+                name: "audit".to_string(),
+                ..Default::default()
+            },
+        );
+        let tables = |s: &SchemaMetadata| s.tables.len();
+
+        assert_eq!(nothing_found(&metadata, &[], "tables", tables), None);
+        assert_eq!(
+            nothing_found(&metadata, &["audit"], "tables", tables).as_deref(),
+            Some("No user tables found in schema(s) audit")
+        );
+        assert_eq!(
+            nothing_found(&metadata, &[], "views", |s| s.views.len()).as_deref(),
+            Some("No user views found in schema(s) audit, sales")
+        );
+        assert_eq!(
+            nothing_found(&DatabaseMetadata::default(), &[], "tables", tables).as_deref(),
+            Some("No user schemas found")
+        );
+    }
+
+    #[test]
+    fn select_as_text_sql_quotes_awkward_identifiers() {
+        let metadata = MetadataBuilder::new()
+            .schema("Tenant Data")
+            .table("User Data", |t| {
+                t.column("camelCase", AxionDataType::Text)
+                    .column("say \"hi\"", AxionDataType::Text)
+            })
+            .build();
+        let table = &metadata.schemas["Tenant Data"].tables["User Data"];
+
+        assert_eq!(
+            select_as_text_sql(table, Dialect::Postgres),
+            "SELECT \"camelCase\"::TEXT AS \"camelCase\", \"say \"\"hi\"\"\"::TEXT AS \"say \"\"hi\"\"\" \
+             FROM \"Tenant Data\".\"User Data\""
+        );
+        assert_eq!(
+            select_as_text_sql(table, Dialect::Mysql),
+            "SELECT CAST(`camelCase` AS CHAR) AS `camelCase`, CAST(`say \"hi\"` AS CHAR) AS `say \"hi\"` \
+             FROM `Tenant Data`.`User Data`"
+        );
+    }
+}
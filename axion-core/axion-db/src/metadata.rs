@@ -1,9 +1,15 @@
 // in axion-db/src/metadata.rs
 
+use crate::codegen::{CodegenOptions, sanitize};
+use crate::error::{DbError, DbResult};
+use crate::sql::{Dialect, quote_ident, quote_literal};
+use chrono::{DateTime, Utc};
 use owo_colors::{OwoColorize, Style};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::fmt; // The essential import for custom formatting
+use std::str::FromStr;
+use std::time::Duration;
 
 // =================================================================================
 //  1. The Formatting Macro: A helper to create clean, aligned key-value output.
@@ -26,9 +32,434 @@ macro_rules! write_field {
 
 // --- Root Metadata Structs ---
 
-#[derive(Clone, Serialize, Deserialize, Default)]
+#[derive(Clone, Serialize, Deserialize, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "camel-case", serde(rename_all = "camelCase"))]
 pub struct DatabaseMetadata {
     pub schemas: HashMap<String, SchemaMetadata>,
+    /// The server the metadata was read from, if it could be determined.
+    #[serde(default)]
+    pub server_info: Option<ServerInfo>,
+}
+
+impl DatabaseMetadata {
+    /// Looks up a schema by name, falling back to a case-insensitive match.
+    /// See `get_case_insensitive` for the matching rules.
+    pub fn schema_ci(&self, name: &str) -> Result<&SchemaMetadata, DbError> {
+        get_case_insensitive(&self.schemas, name, || format!("Schema '{}'", name))
+    }
+
+    /// Counts the entities across all schemas.
+    pub fn stats(&self) -> ModelStats {
+        self.schemas
+            .values()
+            .fold(ModelStats::default(), |mut stats, schema| {
+                stats.schemas += 1;
+                stats.tables += schema.tables.len();
+                stats.views += schema.views.len();
+                stats.enums += schema.enums.len();
+                stats.functions += schema.functions.len();
+                stats
+            })
+    }
+
+    /// Iterates over every table in every schema, in no particular order.
+    pub fn all_tables(&self) -> impl Iterator<Item = &TableMetadata> {
+        self.schemas
+            .values()
+            .flat_map(|schema| schema.tables.values())
+    }
+
+    /// Iterates over every view in every schema, in no particular order.
+    pub fn all_views(&self) -> impl Iterator<Item = &ViewMetadata> {
+        self.schemas
+            .values()
+            .flat_map(|schema| schema.views.values())
+    }
+
+    /// Iterates over every table column as `(schema, table, column)`.
+    /// View columns are not included; use `all_views` for those.
+    pub fn all_columns(&self) -> impl Iterator<Item = (&str, &str, &ColumnMetadata)> {
+        self.all_tables().flat_map(|table| {
+            table
+                .columns
+                .iter()
+                .map(move |col| (table.schema.as_str(), table.name.as_str(), col))
+        })
+    }
+
+    /// Iterates over every enum in every schema, in no particular order.
+    pub fn all_enums(&self) -> impl Iterator<Item = &EnumMetadata> {
+        self.schemas
+            .values()
+            .flat_map(|schema| schema.enums.values())
+    }
+
+    /// Flattens every foreign key in the database into a list of edges,
+    /// sorted by their source so the output is stable.
+    pub fn relationships(&self) -> Vec<Relationship> {
+        let mut edges: Vec<Relationship> = self
+            .all_columns()
+            .filter_map(|(schema, table, col)| {
+                col.foreign_key.as_ref().map(|fk| Relationship {
+                    from_schema: schema.to_string(),
+                    from_table: table.to_string(),
+                    from_column: col.name.clone(),
+                    to_schema: fk.schema.clone(),
+                    to_table: fk.table.clone(),
+                    to_column: fk.column.clone(),
+                })
+            })
+            .collect();
+        edges.sort();
+        edges
+    }
+
+    /// The foreign keys whose referenced table is not in this metadata, usually because
+    /// it lives in a schema that was not introspected. Sorted like `relationships`.
+    pub fn dangling_relationships(&self) -> Vec<Relationship> {
+        let mut edges = self.relationships();
+        edges.retain(|edge| {
+            self.schemas
+                .get(&edge.to_schema)
+                .is_none_or(|schema| !schema.tables.contains_key(&edge.to_table))
+        });
+        edges
+    }
+
+    /// The many-to-many links implied by junction tables (see
+    /// `TableMetadata::is_junction_table`), sorted by junction table.
+    pub fn many_to_many_relationships(&self) -> Vec<ManyToManyRelationship> {
+        let mut links: Vec<ManyToManyRelationship> = self
+            .all_tables()
+            .filter(|table| table.is_junction_table())
+            .filter_map(|table| {
+                let mut sides = table.junction_sides();
+                let right = sides.pop()?;
+                let left = sides.pop()?;
+                Some(ManyToManyRelationship {
+                    junction: table.qualified_name(),
+                    left,
+                    right,
+                })
+            })
+            .collect();
+        links.sort();
+        links
+    }
+
+    /// Lists the table columns present in both snapshots whose type changed between
+    /// `self` and `newer`, sorted by column. Filter on `TypeChange::is_breaking` to keep
+    /// only the changes that can break existing data or clients.
+    pub fn column_type_changes(&self, newer: &DatabaseMetadata) -> Vec<ColumnTypeChange> {
+        let mut changes: Vec<ColumnTypeChange> = self
+            .all_tables()
+            .filter_map(|table| {
+                let new_table = newer.schemas.get(&table.schema)?.tables.get(&table.name)?;
+                Some((table, new_table.columns_by_name()))
+            })
+            .flat_map(|(table, new_columns)| {
+                // Columns are matched by name, so reordering them is not a change.
+                table.columns.iter().filter_map(move |col| {
+                    let new_col = new_columns.get(col.name.as_str())?;
+                    let change = col.axion_type.compatibility(&new_col.axion_type);
+                    (change != TypeChange::Identical).then(|| ColumnTypeChange {
+                        schema: table.schema.clone(),
+                        table: table.name.clone(),
+                        column: col.name.clone(),
+                        from: col.axion_type.clone(),
+                        to: new_col.axion_type.clone(),
+                        change,
+                    })
+                })
+            })
+            .collect();
+        changes.sort_by(|a, b| {
+            (&a.schema, &a.table, &a.column).cmp(&(&b.schema, &b.table, &b.column))
+        });
+        changes
+    }
+
+    /// A 64-bit hash of the metadata's content that does not depend on `HashMap`
+    /// iteration order, so two identical introspections always hash the same.
+    /// Table statistics and the server info are left out, since they change without the
+    /// schema changing (a minor upgrade, or a replica with another database name). Useful for detecting when a cached schema has drifted from the database.
+    pub fn content_hash(&self) -> u64 {
+        let mut value = serde_json::to_value(self).unwrap_or_default();
+        if let Some(object) = value.as_object_mut() {
+            object.remove("server_info");
+            object.remove("serverInfo");
+        }
+        if let Some(schemas) = value.get_mut("schemas").and_then(|s| s.as_object_mut()) {
+            for schema in schemas.values_mut() {
+                if let Some(tables) = schema.get_mut("tables").and_then(|t| t.as_object_mut()) {
+                    for table in tables.values_mut() {
+                        if let Some(table) = table.as_object_mut() {
+                            table.remove("statistics");
+                        }
+                    }
+                }
+            }
+        }
+        fnv1a_64(sort_json_keys(value).to_string().as_bytes())
+    }
+
+    /// Renders a Markdown data dictionary: a section per schema and, for every table and
+    /// view, its comment followed by a table of its columns (name, type, nullable,
+    /// PK/FK and comment). Missing comments are shown as an em dash. Sorted by name.
+    pub fn to_markdown_docs(&self) -> String {
+        MarkdownDocs(self).to_string()
+    }
+}
+
+/// A single foreign-key edge between two columns.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "camel-case", serde(rename_all = "camelCase"))]
+pub struct Relationship {
+    pub from_schema: String,
+    pub from_table: String,
+    pub from_column: String,
+    pub to_schema: String,
+    pub to_table: String,
+    pub to_column: String,
+}
+
+impl fmt::Display for Relationship {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}.{}.{} -> {}.{}.{}",
+            self.from_schema,
+            self.from_table,
+            self.from_column,
+            self.to_schema,
+            self.to_table,
+            self.to_column
+        )
+    }
+}
+
+/// A many-to-many link between two tables through a junction table.
+/// `left` and `right` are the junction's foreign keys to each side, ordered by target table.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "camel-case", serde(rename_all = "camelCase"))]
+pub struct ManyToManyRelationship {
+    pub junction: QualifiedName,
+    pub left: Relationship,
+    pub right: Relationship,
+}
+
+impl fmt::Display for ManyToManyRelationship {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}.{} <-> {}.{} via {}",
+            self.left.to_schema,
+            self.left.to_table,
+            self.right.to_schema,
+            self.right.to_table,
+            self.junction
+        )
+    }
+}
+
+/// A schema-qualified object name, such as `public.users`.
+///
+/// Displays as `schema.name`, quoting either part that contains a dot or a double
+/// quote so the output parses back unchanged. Use `quoted` when building SQL.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "camel-case", serde(rename_all = "camelCase"))]
+pub struct QualifiedName {
+    pub schema: String,
+    pub name: String,
+}
+
+impl QualifiedName {
+    pub fn new(schema: impl Into<String>, name: impl Into<String>) -> Self {
+        Self {
+            schema: schema.into(),
+            name: name.into(),
+        }
+    }
+
+    /// Both parts quoted as SQL identifiers: `"schema"."name"`.
+    pub fn quoted(&self) -> String {
+        self.quoted_in(Dialect::Postgres)
+    }
+
+    /// Both parts quoted as identifiers of `dialect`.
+    pub fn quoted_in(&self, dialect: Dialect) -> String {
+        format!(
+            "{}.{}",
+            dialect.quote_ident(&self.schema),
+            dialect.quote_ident(&self.name)
+        )
+    }
+}
+
+impl fmt::Display for QualifiedName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let part = |ident: &str| {
+            if ident.contains(['.', '"']) {
+                quote_ident(ident)
+            } else {
+                ident.to_string()
+            }
+        };
+        write!(f, "{}.{}", part(&self.schema), part(&self.name))
+    }
+}
+
+impl FromStr for QualifiedName {
+    type Err = DbError;
+
+    /// Parses `schema.name`, splitting on the last dot outside double quotes.
+    /// Quoted parts may contain dots and `""`-escaped quotes.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || DbError::InvalidInput(format!("Invalid qualified name: '{}'", s));
+
+        let mut in_quotes = false;
+        let mut split_at = None;
+        for (i, c) in s.char_indices() {
+            match c {
+                '"' => in_quotes = !in_quotes,
+                '.' if !in_quotes => split_at = Some(i),
+                _ => {}
+            }
+        }
+        if in_quotes {
+            return Err(invalid());
+        }
+        let split_at = split_at.ok_or_else(invalid)?;
+
+        let unquote = |part: &str| -> Option<String> {
+            match part.strip_prefix('"').and_then(|p| p.strip_suffix('"')) {
+                Some(inner) => Some(inner.replace("\"\"", "\"")),
+                None if part.is_empty() || part.contains('"') => None,
+                None => Some(part.to_string()),
+            }
+        };
+        let schema = unquote(&s[..split_at]).ok_or_else(invalid)?;
+        let name = unquote(&s[split_at + 1..]).ok_or_else(invalid)?;
+        Ok(Self { schema, name })
+    }
+}
+
+/// A name-level difference between cached metadata and the live database.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum SchemaDrift {
+    /// A cached schema no longer exists.
+    SchemaDropped(String),
+    /// A schema exists that the cached metadata does not know about.
+    SchemaAdded(String),
+    /// A cached table or view no longer exists.
+    RelationDropped { schema: String, name: String },
+    /// A table or view exists that the cached metadata does not know about.
+    RelationAdded { schema: String, name: String },
+}
+
+impl fmt::Display for SchemaDrift {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SchemaDrift::SchemaDropped(schema) => write!(f, "schema '{}' was dropped", schema),
+            SchemaDrift::SchemaAdded(schema) => write!(f, "schema '{}' was added", schema),
+            SchemaDrift::RelationDropped { schema, name } => {
+                write!(f, "relation '{}.{}' was dropped", schema, name)
+            }
+            SchemaDrift::RelationAdded { schema, name } => {
+                write!(f, "relation '{}.{}' was added", schema, name)
+            }
+        }
+    }
+}
+
+/// Rebuilds every JSON object with its keys in sorted order.
+fn sort_json_keys(value: serde_json::Value) -> serde_json::Value {
+    use serde_json::Value;
+    match value {
+        Value::Object(map) => {
+            let mut entries: Vec<_> = map.into_iter().collect();
+            entries.sort_by(|(a, _), (b, _)| a.cmp(b));
+            Value::Object(
+                entries
+                    .into_iter()
+                    .map(|(k, v)| (k, sort_json_keys(v)))
+                    .collect(),
+            )
+        }
+        Value::Array(items) => Value::Array(items.into_iter().map(sort_json_keys).collect()),
+        other => other,
+    }
+}
+
+/// FNV-1a, used instead of `DefaultHasher` because its output is stable across
+/// Rust versions and processes.
+fn fnv1a_64(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+        (hash ^ u64::from(*byte)).wrapping_mul(0x0100_0000_01b3)
+    })
+}
+
+struct MarkdownDocs<'a>(&'a DatabaseMetadata);
+
+impl fmt::Display for MarkdownDocs<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "# Data Dictionary")?;
+        if self.0.schemas.is_empty() {
+            writeln!(f, "\n_No user schemas found._")?;
+        }
+
+        let mut schemas: Vec<_> = self.0.schemas.values().collect();
+        schemas.sort_by(|a, b| a.name.cmp(&b.name));
+        for schema in schemas {
+            writeln!(f, "\n## Schema `{}`", schema.name)?;
+
+            let mut relations: Vec<Relation> = schema.relations().collect();
+            relations.sort_by_key(|relation| relation.name());
+            if relations.is_empty() {
+                writeln!(f, "\n_No tables or views._")?;
+            }
+
+            for relation in relations {
+                let suffix = if relation.is_view() { " (view)" } else { "" };
+                writeln!(f, "\n### `{}`{}\n", relation.qualified_name(), suffix)?;
+                writeln!(f, "{}\n", markdown_cell(relation.comment()))?;
+                writeln!(f, "| Column | Type | Nullable | Key | Comment |")?;
+                writeln!(f, "| --- | --- | --- | --- | --- |")?;
+                for col in relation.columns() {
+                    let sql_type = if col.canonical_sql_type.is_empty() {
+                        &col.sql_type_name
+                    } else {
+                        &col.canonical_sql_type
+                    };
+                    let mut keys = Vec::new();
+                    if col.is_primary_key {
+                        keys.push("PK".to_string());
+                    }
+                    if let Some(fk) = &col.foreign_key {
+                        keys.push(format!("FK → `{}`", fk));
+                    }
+                    writeln!(
+                        f,
+                        "| `{}` | `{}` | {} | {} | {} |",
+                        col.name,
+                        sql_type,
+                        if col.is_nullable { "Yes" } else { "No" },
+                        keys.join(", "),
+                        markdown_cell(col.comment.as_deref())
+                    )?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Makes free text safe inside a Markdown table cell, or an em dash when there is none.
+fn markdown_cell(text: Option<&str>) -> String {
+    match text.map(str::trim).filter(|t| !t.is_empty()) {
+        Some(text) => text.replace('|', "\\|").replace('\n', "<br>"),
+        None => "—".to_string(),
+    }
 }
 
 impl fmt::Display for DatabaseMetadata {
@@ -40,6 +471,9 @@ impl fmt::Display for DatabaseMetadata {
 impl fmt::Debug for DatabaseMetadata {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         writeln!(f, "DatabaseMetadata ({} schemas):", self.schemas.len())?;
+        if let Some(server_info) = &self.server_info {
+            write_field!(f, "Server", server_info.to_string())?;
+        }
         for (name, schema) in &self.schemas {
             writeln!(f, "{:#?}", schema)?;
         }
@@ -47,15 +481,137 @@ impl fmt::Debug for DatabaseMetadata {
     }
 }
 
-#[derive(Clone, Serialize, Deserialize, Default)]
+/// Facts about the database server, captured when introspection starts.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "camel-case", serde(rename_all = "camelCase"))]
+pub struct ServerInfo {
+    /// The human-readable server version, e.g. `16.2`.
+    pub version: String,
+    /// The version as a single comparable number, e.g. `160002` for 16.2 or `90624` for 9.6.24.
+    pub version_num: u32,
+    pub server_encoding: String,
+    /// The database (catalog) the client is connected to.
+    pub database_name: String,
+}
+
+impl fmt::Display for ServerInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} (database '{}', encoding {})",
+            self.version, self.database_name, self.server_encoding
+        )
+    }
+}
+
+/// A table or a view, with the accessors the two have in common.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Relation<'a> {
+    Table(&'a TableMetadata),
+    View(&'a ViewMetadata),
+}
+
+impl<'a> Relation<'a> {
+    pub fn name(&self) -> &'a str {
+        match self {
+            Relation::Table(table) => &table.name,
+            Relation::View(view) => &view.name,
+        }
+    }
+
+    pub fn schema(&self) -> &'a str {
+        match self {
+            Relation::Table(table) => &table.schema,
+            Relation::View(view) => &view.schema,
+        }
+    }
+
+    pub fn columns(&self) -> &'a [ColumnMetadata] {
+        match self {
+            Relation::Table(table) => &table.columns,
+            Relation::View(view) => &view.columns,
+        }
+    }
+
+    pub fn comment(&self) -> Option<&'a str> {
+        match self {
+            Relation::Table(table) => table.comment.as_deref(),
+            Relation::View(view) => view.comment.as_deref(),
+        }
+    }
+
+    pub fn qualified_name(&self) -> QualifiedName {
+        QualifiedName::new(self.schema(), self.name())
+    }
+
+    pub fn is_view(&self) -> bool {
+        matches!(self, Relation::View(_))
+    }
+}
+
+#[derive(Clone, Serialize, Deserialize, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "camel-case", serde(rename_all = "camelCase"))]
 pub struct SchemaMetadata {
     pub name: String,
     pub tables: HashMap<String, TableMetadata>,
     pub views: HashMap<String, ViewMetadata>,
     pub enums: HashMap<String, EnumMetadata>,
+    #[serde(default)]
+    pub composite_types: HashMap<String, CompositeTypeMetadata>,
+    /// Routines by name. Overloaded routines are keyed by their signature instead,
+    /// e.g. `discount(numeric, integer)`.
     pub functions: HashMap<String, FunctionMetadata>,
 }
 
+impl SchemaMetadata {
+    /// Iterates over the tables and then the views of this schema, for code that treats
+    /// both the same way. Order within each kind is unspecified.
+    pub fn relations(&self) -> impl Iterator<Item = Relation<'_>> {
+        self.tables
+            .values()
+            .map(Relation::Table)
+            .chain(self.views.values().map(Relation::View))
+    }
+
+    /// Looks up a table by name, falling back to a case-insensitive match.
+    /// See `get_case_insensitive` for the matching rules.
+    pub fn table_ci(&self, name: &str) -> Result<&TableMetadata, DbError> {
+        get_case_insensitive(&self.tables, name, || {
+            format!("Table '{}.{}'", self.name, name)
+        })
+    }
+}
+
+/// Returns the entry for `name`, or failing that, the single entry whose key equals
+/// `name` ignoring ASCII case. Fails with `NotFound` when nothing matches and with
+/// `Ambiguous` when several keys differ from `name` only by case.
+fn get_case_insensitive<'a, V>(
+    map: &'a HashMap<String, V>,
+    name: &str,
+    describe: impl Fn() -> String,
+) -> Result<&'a V, DbError> {
+    if let Some(value) = map.get(name) {
+        return Ok(value);
+    }
+    let mut matches: Vec<(&String, &V)> = map
+        .iter()
+        .filter(|(key, _)| key.eq_ignore_ascii_case(name))
+        .collect();
+    match matches.len() {
+        0 => Err(DbError::NotFound(describe())),
+        1 => Ok(matches.remove(0).1),
+        _ => {
+            let mut keys: Vec<&str> = matches.iter().map(|(key, _)| key.as_str()).collect();
+            keys.sort_unstable();
+            Err(DbError::Ambiguous(format!(
+                "{} matches {} when ignoring case",
+                describe(),
+                keys.join(", ")
+            )))
+        }
+    }
+}
+
 impl fmt::Display for SchemaMetadata {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(
@@ -75,11 +631,81 @@ impl fmt::Debug for SchemaMetadata {
         write_field!(f, "Tables", self.tables, collection)?;
         write_field!(f, "Views", self.views, collection)?;
         write_field!(f, "Enums", self.enums, collection)?;
+        write_field!(f, "Composite Types", self.composite_types, collection)?;
         write_field!(f, "Functions", self.functions, collection)?;
         Ok(())
     }
 }
 
+/// Entity counts across the whole database.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "camel-case", serde(rename_all = "camelCase"))]
+pub struct ModelStats {
+    pub schemas: usize,
+    pub tables: usize,
+    pub views: usize,
+    pub enums: usize,
+    pub functions: usize,
+}
+
+/// Entity counts gathered by `Introspector::survey`, without introspecting any columns.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "camel-case", serde(rename_all = "camelCase"))]
+pub struct SurveyReport {
+    /// Counts for each surveyed schema, keyed by schema name. `schemas` is always 1.
+    pub per_schema: BTreeMap<String, ModelStats>,
+}
+
+impl SurveyReport {
+    /// Sums the per-schema counts.
+    pub fn totals(&self) -> ModelStats {
+        self.per_schema
+            .values()
+            .fold(ModelStats::default(), |acc, stats| ModelStats {
+                schemas: acc.schemas + stats.schemas,
+                tables: acc.tables + stats.tables,
+                views: acc.views + stats.views,
+                enums: acc.enums + stats.enums,
+                functions: acc.functions + stats.functions,
+            })
+    }
+}
+
+/// Timing information collected while introspecting a database.
+#[derive(Debug, Clone, Default)]
+pub struct IntrospectionReport {
+    /// Wall-clock time spent on the whole introspection run.
+    pub total_duration: Duration,
+    /// Time spent introspecting each schema, keyed by schema name.
+    pub per_schema: HashMap<String, Duration>,
+    /// When the introspection run finished.
+    pub finished_at: Option<DateTime<Utc>>,
+    /// Schemas, tables and views that could not be introspected and were left out.
+    pub skipped: Vec<SkippedEntity>,
+    /// Foreign keys pointing at tables outside the introspected schemas.
+    pub dangling_relationships: Vec<Relationship>,
+}
+
+/// A schema or relation that introspection could not read and left out of the metadata.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "camel-case", serde(rename_all = "camelCase"))]
+pub struct SkippedEntity {
+    pub schema: String,
+    /// The table or view name, or `None` if the whole schema was skipped.
+    pub name: Option<String>,
+    /// The error that caused the entity to be skipped.
+    pub reason: String,
+}
+
+impl fmt::Display for SkippedEntity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.name {
+            Some(name) => write!(f, "{}.{}: {}", self.schema, name, self.reason),
+            None => write!(f, "schema {}: {}", self.schema, self.reason),
+        }
+    }
+}
+
 // --- Type and Reference Structs ---
 
 #[derive(Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
@@ -99,7 +725,25 @@ pub enum AxionDataType {
     JsonB,
     Inet,
     Enum(String),
+    /// A composite (row) type created with `CREATE TYPE ... AS (...)`.
+    Composite(String),
     Array(Box<AxionDataType>),
+    /// A user-defined domain, mapped through to its underlying base type.
+    Domain {
+        name: String,
+        base: Box<AxionDataType>,
+    },
+    /// A PostGIS `geometry`, optionally constrained to a subtype (`Point`, `Polygon`, ...)
+    /// and a spatial reference system.
+    Geometry {
+        srid: Option<i32>,
+        subtype: Option<String>,
+    },
+    /// A PostGIS `geography`, with the same optional constraints as `Geometry`.
+    Geography {
+        srid: Option<i32>,
+        subtype: Option<String>,
+    },
     Unsupported(String),
 }
 
@@ -122,28 +766,420 @@ impl fmt::Display for AxionDataType {
             Self::JsonB => write!(f, "JSONB"),
             Self::Inet => write!(f, "INET"),
             Self::Enum(name) => write!(f, "{}", name),
+            Self::Composite(name) => write!(f, "COMPOSITE({})", name),
             Self::Array(inner) => write!(f, "{}[]", inner),
+            Self::Domain { name, base } => write!(f, "{}({})", name, base),
+            Self::Geometry { srid, subtype } => write_spatial(f, "GEOMETRY", *srid, subtype),
+            Self::Geography { srid, subtype } => write_spatial(f, "GEOGRAPHY", *srid, subtype),
             Self::Unsupported(name) => write!(f, "UNSUPPORTED({})", name),
         }
     }
 }
+
+/// Writes a spatial type the way Postgres declares it, e.g. `GEOMETRY(Point,4326)`.
+/// An SRID without a subtype is written with PostGIS's generic `Geometry` subtype.
+fn write_spatial(
+    f: &mut fmt::Formatter<'_>,
+    keyword: &str,
+    srid: Option<i32>,
+    subtype: &Option<String>,
+) -> fmt::Result {
+    let subtype = subtype.as_deref();
+    match (subtype, srid) {
+        (_, Some(srid)) => write!(f, "{}({},{})", keyword, subtype.unwrap_or("Geometry"), srid),
+        (Some(subtype), None) => write!(f, "{}({})", keyword, subtype),
+        (None, None) => f.write_str(keyword),
+    }
+}
+
+/// Reads the subtype and SRID out of spatial type modifiers such as `Point,4326`.
+/// PostGIS's generic `Geometry` subtype means no subtype constraint.
+fn spatial_modifiers(modifiers: &str) -> (Option<String>, Option<i32>) {
+    let mut parts = modifiers.split(',').map(str::trim);
+    let subtype = parts
+        .next()
+        .filter(|subtype| !subtype.is_empty() && !subtype.eq_ignore_ascii_case("geometry"))
+        .map(str::to_string);
+    let srid = parts.next().and_then(|srid| srid.parse().ok());
+    (subtype, srid)
+}
 // Debug for AxionDataType will be the verbose, struct-like representation
 impl fmt::Debug for AxionDataType {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             Self::Enum(name) => f.debug_tuple("Enum").field(name).finish(),
+            Self::Composite(name) => f.debug_tuple("Composite").field(name).finish(),
             Self::Array(inner) => f.debug_tuple("Array").field(inner).finish(),
+            Self::Domain { name, base } => f
+                .debug_struct("Domain")
+                .field("name", name)
+                .field("base", base)
+                .finish(),
+            Self::Geometry { srid, subtype } => f
+                .debug_struct("Geometry")
+                .field("srid", srid)
+                .field("subtype", subtype)
+                .finish(),
+            Self::Geography { srid, subtype } => f
+                .debug_struct("Geography")
+                .field("srid", srid)
+                .field("subtype", subtype)
+                .finish(),
             Self::Unsupported(name) => f.debug_tuple("Unsupported").field(name).finish(),
             _ => write!(f, "{}", self), // For simple variants, Display and Debug are the same
         }
     }
 }
 
+impl AxionDataType {
+    /// The Rust type used to hold a value of this type, e.g. `i32`, `Vec<uuid::Uuid>`
+    /// or `Option<serde_json::Value>` when `nullable` is set.
+    /// This is the single mapping shared by code generation and row decoding.
+    /// Enums and composite types are named as `CodegenOptions::type_names` would name
+    /// them without collisions; use `resolved_rust_type` to match generated declarations.
+    pub fn rust_type(&self, nullable: bool) -> String {
+        self.rust_type_with(nullable, &|name| sanitize(&to_pascal_case(name)))
+    }
+
+    /// Like `rust_type`, but enums and composite types take their name from `type_names`
+    /// (as returned by `CodegenOptions::type_names`), so references agree with the
+    /// generated declarations after truncation and collision suffixes. A type is looked up
+    /// in `schema` first, then by name if exactly one schema defines it.
+    pub fn resolved_rust_type(
+        &self,
+        nullable: bool,
+        schema: &str,
+        type_names: &BTreeMap<QualifiedName, String>,
+    ) -> String {
+        self.rust_type_with(nullable, &|name| {
+            let resolved = type_names
+                .get(&QualifiedName::new(schema, name))
+                .or_else(|| {
+                    let mut matches = type_names
+                        .iter()
+                        .filter(|(object, _)| object.name == name)
+                        .map(|(_, ident)| ident);
+                    matches.next().filter(|_| matches.next().is_none())
+                });
+            match resolved {
+                Some(ident) => ident.clone(),
+                None => sanitize(&to_pascal_case(name)),
+            }
+        })
+    }
+
+    fn rust_type_with(&self, nullable: bool, user_type: &dyn Fn(&str) -> String) -> String {
+        let base = match self {
+            Self::Text => "String".to_string(),
+            Self::Integer(16) => "i16".to_string(),
+            Self::Integer(32) => "i32".to_string(),
+            Self::Integer(_) => "i64".to_string(),
+            Self::Float(32) => "f32".to_string(),
+            Self::Float(_) => "f64".to_string(),
+            Self::Numeric => "rust_decimal::Decimal".to_string(),
+            Self::Boolean => "bool".to_string(),
+            Self::Timestamp => "chrono::NaiveDateTime".to_string(),
+            Self::TimestampTz => "chrono::DateTime<chrono::Utc>".to_string(),
+            Self::Date => "chrono::NaiveDate".to_string(),
+            Self::Time => "chrono::NaiveTime".to_string(),
+            Self::Bytes => "Vec<u8>".to_string(),
+            Self::Uuid => "uuid::Uuid".to_string(),
+            Self::Json | Self::JsonB => "serde_json::Value".to_string(),
+            Self::Inet => "ipnetwork::IpNetwork".to_string(),
+            Self::Enum(name) | Self::Composite(name) => user_type(name),
+            // Array elements are not nullable in the generated type
+            Self::Array(inner) => format!("Vec<{}>", inner.rust_type_with(false, user_type)),
+            Self::Domain { base, .. } => base.rust_type_with(false, user_type),
+            // PostGIS sends spatial values in their hex-encoded EWKB text form
+            Self::Geometry { .. } | Self::Geography { .. } => "String".to_string(),
+            Self::Unsupported(name) => format!("serde_json::Value /* unsupported: {} */", name),
+        };
+        if nullable {
+            format!("Option<{}>", base)
+        } else {
+            base
+        }
+    }
+
+    /// Classifies a change of a column's type from `self` to `new`.
+    /// Only changes within one family count as widening or narrowing (a bigger integer, a
+    /// float with more precision, an integer becoming `NUMERIC`, dropping a domain); a
+    /// change of family, such as `INT4 -> TEXT`, is incompatible even when the database
+    /// could cast it, because clients see values of a different type.
+    pub fn compatibility(&self, new: &AxionDataType) -> TypeChange {
+        use AxionDataType::*;
+        match (self, new) {
+            _ if self == new => TypeChange::Identical,
+            (Integer(from), Integer(to)) | (Float(from), Float(to)) => {
+                if to > from {
+                    TypeChange::Widening
+                } else {
+                    TypeChange::Narrowing
+                }
+            }
+            (Integer(_), Numeric) => TypeChange::Widening,
+            (Numeric, Integer(_)) => TypeChange::Narrowing,
+            (Array(from), Array(to)) => from.compatibility(to),
+            (
+                Domain {
+                    name: from_name,
+                    base: from,
+                },
+                Domain {
+                    name: to_name,
+                    base: to,
+                },
+            ) if from_name == to_name => from.compatibility(to),
+            // Constraints of an unknown domain may reject values the old type allowed.
+            (Domain { base: from, .. }, Domain { base: to, .. }) => {
+                from.compatibility(to).max(TypeChange::Narrowing)
+            }
+            (Domain { base, .. }, _) => base.compatibility(new).max(TypeChange::Widening),
+            (_, Domain { base, .. }) => self.compatibility(base).max(TypeChange::Narrowing),
+            (
+                Geometry {
+                    srid: from_srid,
+                    subtype: from_subtype,
+                },
+                Geometry {
+                    srid: to_srid,
+                    subtype: to_subtype,
+                },
+            )
+            | (
+                Geography {
+                    srid: from_srid,
+                    subtype: from_subtype,
+                },
+                Geography {
+                    srid: to_srid,
+                    subtype: to_subtype,
+                },
+            ) => {
+                // Dropping a constraint accepts everything the old column held.
+                let keeps_srid = to_srid.is_none() || to_srid == from_srid;
+                let keeps_subtype = to_subtype.is_none() || to_subtype == from_subtype;
+                if keeps_srid && keeps_subtype {
+                    TypeChange::Widening
+                } else {
+                    TypeChange::Narrowing
+                }
+            }
+            _ => TypeChange::Incompatible,
+        }
+    }
+
+    /// Fills in the subtype and SRID of a spatial type (or an array of them) from the
+    /// column's full SQL type as printed by `format_type`, e.g. `geometry(Point,4326)`.
+    /// Reading the type modifier this way also works for views and for columns missing
+    /// from PostGIS's `geometry_columns`. Other types are returned unchanged.
+    pub fn with_spatial_modifiers(self, sql_type: &str) -> Self {
+        let modifiers = sql_type
+            .split_once('(')
+            .and_then(|(_, rest)| rest.split(')').next());
+        match (self, modifiers) {
+            (Self::Geometry { .. }, Some(modifiers)) => {
+                let (subtype, srid) = spatial_modifiers(modifiers);
+                Self::Geometry { srid, subtype }
+            }
+            (Self::Geography { .. }, Some(modifiers)) => {
+                let (subtype, srid) = spatial_modifiers(modifiers);
+                Self::Geography { srid, subtype }
+            }
+            (Self::Array(inner), _) => {
+                Self::Array(Box::new(inner.with_spatial_modifiers(sql_type)))
+            }
+            (other, _) => other,
+        }
+    }
+}
+
+/// How a column's type changed, from safest to most disruptive.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum TypeChange {
+    Identical,
+    /// Every value of the old type is still valid, e.g. `INT4 -> INT8`.
+    Widening,
+    /// Some values of the old type may no longer fit, e.g. `INT8 -> INT4`.
+    Narrowing,
+    /// The types are unrelated, e.g. `TEXT -> INT4`.
+    Incompatible,
+}
+
+impl TypeChange {
+    /// Whether existing data or clients may break: narrowing and incompatible changes.
+    pub fn is_breaking(self) -> bool {
+        self >= TypeChange::Narrowing
+    }
+}
+
+impl fmt::Display for TypeChange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let label = match self {
+            TypeChange::Identical => "identical",
+            TypeChange::Widening => "widening",
+            TypeChange::Narrowing => "narrowing",
+            TypeChange::Incompatible => "incompatible",
+        };
+        f.write_str(label)
+    }
+}
+
+/// A table column whose type differs between two metadata snapshots.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "camel-case", serde(rename_all = "camelCase"))]
+pub struct ColumnTypeChange {
+    pub schema: String,
+    pub table: String,
+    pub column: String,
+    pub from: AxionDataType,
+    pub to: AxionDataType,
+    pub change: TypeChange,
+}
+
+impl fmt::Display for ColumnTypeChange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}.{}.{}: {} -> {} ({})",
+            self.schema, self.table, self.column, self.from, self.to, self.change
+        )
+    }
+}
+
+/// Converts a snake_case (or otherwise delimited) SQL name into a PascalCase Rust type name.
+pub(crate) fn to_pascal_case(name: &str) -> String {
+    name.split(|c: char| !c.is_alphanumeric())
+        .filter(|part| !part.is_empty())
+        .map(|part| {
+            let mut chars = part.chars();
+            match chars.next() {
+                Some(first) => first.to_uppercase().chain(chars).collect::<String>(),
+                None => String::new(),
+            }
+        })
+        .collect()
+}
+
+// FromStr parses the compact Display form back, so `x.to_string().parse() == Ok(x)`.
+// Names that are not one of the built-in keywords are read as enum names.
+impl FromStr for AxionDataType {
+    type Err = DbError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        if s.is_empty() {
+            return Err(DbError::InvalidInput("empty data type".to_string()));
+        }
+        if let Some(inner) = s.strip_suffix("[]") {
+            return Ok(Self::Array(Box::new(inner.parse()?)));
+        }
+        if let Some(name) = s
+            .strip_prefix("UNSUPPORTED(")
+            .and_then(|rest| rest.strip_suffix(')'))
+        {
+            return Ok(Self::Unsupported(name.to_string()));
+        }
+        if let Some(name) = s
+            .strip_prefix("COMPOSITE(")
+            .and_then(|rest| rest.strip_suffix(')'))
+        {
+            return Ok(Self::Composite(name.to_string()));
+        }
+        for keyword in ["GEOMETRY", "GEOGRAPHY"] {
+            let Some(rest) = s.strip_prefix(keyword) else {
+                continue;
+            };
+            let modifiers = match rest.strip_prefix('(').and_then(|r| r.strip_suffix(')')) {
+                Some(modifiers) => modifiers,
+                None if rest.is_empty() => "",
+                None => break,
+            };
+            let (subtype, srid) = spatial_modifiers(modifiers);
+            return Ok(match keyword {
+                "GEOMETRY" => Self::Geometry { srid, subtype },
+                _ => Self::Geography { srid, subtype },
+            });
+        }
+        if let (Some(open), true) = (s.find('('), s.ends_with(')')) {
+            let name = &s[..open];
+            if name.is_empty() {
+                return Err(DbError::InvalidInput(format!(
+                    "missing domain name in '{}'",
+                    s
+                )));
+            }
+            return Ok(Self::Domain {
+                name: name.to_string(),
+                base: Box::new(s[open + 1..s.len() - 1].parse()?),
+            });
+        }
+
+        let parsed = match s {
+            "TEXT" => Self::Text,
+            "NUMERIC" => Self::Numeric,
+            "BOOL" => Self::Boolean,
+            "TIMESTAMP" => Self::Timestamp,
+            "TIMESTAMPTZ" => Self::TimestampTz,
+            "DATE" => Self::Date,
+            "TIME" => Self::Time,
+            "BYTES" => Self::Bytes,
+            "UUID" => Self::Uuid,
+            "JSON" => Self::Json,
+            "JSONB" => Self::JsonB,
+            "INET" => Self::Inet,
+            _ => {
+                if let Some(bits) = s.strip_prefix("INT").and_then(|b| b.parse().ok()) {
+                    Self::Integer(bits)
+                } else if let Some(bits) = s.strip_prefix("FLOAT").and_then(|b| b.parse().ok()) {
+                    Self::Float(bits)
+                } else if s.contains(['(', ')', '[', ']']) {
+                    return Err(DbError::InvalidInput(format!(
+                        "malformed data type '{}'",
+                        s
+                    )));
+                } else {
+                    Self::Enum(s.to_string())
+                }
+            }
+        };
+        Ok(parsed)
+    }
+}
+
 #[derive(Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "camel-case", serde(rename_all = "camelCase"))]
 pub struct ForeignKeyReference {
     pub schema: String,
     pub table: String,
     pub column: String,
+    #[serde(default)]
+    pub on_delete: ReferentialAction,
+    #[serde(default)]
+    pub on_update: ReferentialAction,
+}
+
+/// What happens to referencing rows when the referenced row is deleted or updated.
+#[derive(Debug, Default, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub enum ReferentialAction {
+    #[default]
+    NoAction,
+    Restrict,
+    Cascade,
+    SetNull,
+    SetDefault,
+}
+
+impl fmt::Display for ReferentialAction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            ReferentialAction::NoAction => "NO ACTION",
+            ReferentialAction::Restrict => "RESTRICT",
+            ReferentialAction::Cascade => "CASCADE",
+            ReferentialAction::SetNull => "SET NULL",
+            ReferentialAction::SetDefault => "SET DEFAULT",
+        })
+    }
 }
 // Let's create a compact display for FKs
 impl fmt::Display for ForeignKeyReference {
@@ -158,6 +1194,8 @@ impl fmt::Debug for ForeignKeyReference {
             .field("schema", &self.schema)
             .field("table", &self.table)
             .field("column", &self.column)
+            .field("on_delete", &format_args!("{}", self.on_delete))
+            .field("on_update", &format_args!("{}", self.on_update))
             .finish()
     }
 }
@@ -165,21 +1203,83 @@ impl fmt::Debug for ForeignKeyReference {
 // --- Core Entity Structs ---
 
 #[derive(Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "camel-case", serde(rename_all = "camelCase"))]
 pub struct ColumnMetadata {
     pub name: String,
     pub sql_type_name: String,
+    /// The exact type as Postgres prints it (`format_type`), with length, precision and
+    /// schema qualification kept, e.g. `character varying(255)` or `numeric(10,2)`.
+    /// Array types always read as one level (`integer[]`); see `AxionDataType` for nesting.
+    #[serde(default)]
+    pub canonical_sql_type: String,
     pub axion_type: AxionDataType,
     pub is_nullable: bool,
     pub is_primary_key: bool,
+    /// Whether the value is generated from a sequence (`serial` or an identity column),
+    /// so it can be left out of inserts.
+    #[serde(default)]
+    pub is_auto_increment: bool,
     pub default_value: Option<String>,
+    /// The column's collation, when it differs from the database default.
+    #[serde(default)]
+    pub collation: Option<String>,
     pub comment: Option<String>,
     pub foreign_key: Option<ForeignKeyReference>,
+    /// Whether this is a hidden system column such as `ctid` or `xmin`.
+    #[serde(default)]
+    pub is_system: bool,
 }
 // This provides the `column_name    VARCHAR(255)    TEXT` format
 
 // This provides the `column_name    VARCHAR(255)    TEXT` format
-impl fmt::Display for ColumnMetadata {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+/// The widths used to align the name and type columns when printing `ColumnMetadata`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ColumnWidths {
+    pub name: usize,
+    pub sql_type: usize,
+    pub axion_type: usize,
+}
+
+impl Default for ColumnWidths {
+    fn default() -> Self {
+        Self {
+            name: 25,
+            sql_type: 20,
+            axion_type: 20,
+        }
+    }
+}
+
+impl ColumnWidths {
+    /// Widths that fit the widest name and types among `columns`, plus a two-space gap.
+    pub fn fit(columns: &[ColumnMetadata]) -> Self {
+        let widest =
+            |len: fn(&ColumnMetadata) -> usize| columns.iter().map(len).max().unwrap_or(0) + 2;
+        Self {
+            name: widest(|c| c.name.chars().count()),
+            sql_type: widest(|c| c.sql_type_name.chars().count()),
+            axion_type: widest(|c| c.axion_type.to_string().chars().count()),
+        }
+    }
+}
+
+impl ColumnMetadata {
+    /// The type to cast a text value to when comparing it with this column, or `""` to
+    /// compare the column's text form instead: `json` and unsupported types may have no
+    /// equality operator.
+    pub(crate) fn comparison_sql_type(&self) -> &str {
+        match self.axion_type {
+            AxionDataType::Json | AxionDataType::Unsupported(_) => "",
+            _ => &self.canonical_sql_type,
+        }
+    }
+
+    /// Displays the column like its `Display` impl, but aligned to the given widths.
+    pub fn display_with(&self, widths: ColumnWidths) -> impl fmt::Display + '_ {
+        AlignedColumn(self, widths)
+    }
+
+    fn fmt_aligned(&self, f: &mut fmt::Formatter<'_>, widths: ColumnWidths) -> fmt::Result {
         // Define styles for different parts
         let pk_style = Style::new().green().bold();
         let fk_style = Style::new().cyan();
@@ -196,10 +1296,21 @@ impl fmt::Display for ColumnMetadata {
             Box::new("*".style(nullable_style))
         };
         // ===============================================================
-        write!(f, "  {} {:<25}", nullable_marker, self.name.bold())?;
+        write!(
+            f,
+            "  {} {:<width$}",
+            nullable_marker,
+            self.name.bold(),
+            width = widths.name
+        )?;
 
         // 2. SQL Type
-        write!(f, "{:<20}", self.sql_type_name.dimmed())?;
+        write!(
+            f,
+            "{:<width$}",
+            self.sql_type_name.dimmed(),
+            width = widths.sql_type
+        )?;
 
         // 3. Axion Type (with special coloring for enums)
         let binding = self.axion_type.to_string();
@@ -208,7 +1319,12 @@ impl fmt::Display for ColumnMetadata {
             _ => Box::new(binding.style(type_style)),
         };
 
-        write!(f, "{:<20}", axion_type_display)?;
+        write!(
+            f,
+            "{:<width$}",
+            axion_type_display,
+            width = widths.axion_type
+        )?;
 
         // 4. Constraints (PK, FK)
         let mut constraints = Vec::new();
@@ -223,41 +1339,173 @@ impl fmt::Display for ColumnMetadata {
         Ok(())
     }
 }
+impl fmt::Display for ColumnMetadata {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_aligned(f, ColumnWidths::default())
+    }
+}
+
+struct AlignedColumn<'a>(&'a ColumnMetadata, ColumnWidths);
+
+impl fmt::Display for AlignedColumn<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt_aligned(f, self.1)
+    }
+}
 impl fmt::Debug for ColumnMetadata {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         writeln!(f, "Column '{}':", self.name)?;
         write_field!(f, "SQL Type", &self.sql_type_name)?;
+        write_field!(f, "Canonical Type", &self.canonical_sql_type)?;
         write_field!(f, "Axion Type", &self.axion_type)?;
         write_field!(f, "Nullable", &self.is_nullable)?;
         write_field!(f, "Primary Key", &self.is_primary_key)?;
+        write_field!(f, "Auto Increment", &self.is_auto_increment)?;
         write_field!(f, "Default", &self.default_value)?;
+        write_field!(f, "Collation", &self.collation)?;
         write_field!(f, "Foreign Key", &self.foreign_key)?;
+        write_field!(f, "System", &self.is_system)?;
         write_field!(f, "Comment", &self.comment)
     }
 }
 
-#[derive(Clone, Serialize, Deserialize, Default)]
+#[derive(Clone, Serialize, Deserialize, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "camel-case", serde(rename_all = "camelCase"))]
 pub struct TableMetadata {
     pub name: String,
     pub schema: String,
     pub columns: Vec<ColumnMetadata>,
     pub primary_key_columns: Vec<String>,
     pub comment: Option<String>,
+    /// Storage statistics, only populated when requested in the introspection options.
+    pub statistics: Option<TableStatistics>,
+    /// Whether this is a declaratively partitioned parent table.
+    #[serde(default)]
+    pub is_partitioned: bool,
+    /// The `schema.table` name of the parent, if this table is a partition.
+    pub partition_of: Option<String>,
+    /// The partition bound expression (e.g. `FOR VALUES FROM (...) TO (...)`).
+    pub partition_bound: Option<String>,
+    /// User-defined triggers on this table, sorted by name.
+    #[serde(default)]
+    pub triggers: Vec<TriggerMetadata>,
+    /// Indexes over plain columns, sorted by name. Partial and expression indexes are omitted.
+    #[serde(default)]
+    pub indexes: Vec<IndexMetadata>,
+    /// Whether row-level security is enabled on this table.
+    #[serde(default)]
+    pub rls_enabled: bool,
+    /// Row-level security policies on this table, sorted by name.
+    #[serde(default)]
+    pub policies: Vec<RlsPolicy>,
 }
+
+/// How many non-key columns a table may carry and still count as a junction table.
+pub const JUNCTION_MAX_EXTRA_COLUMNS: usize = 2;
+
+impl TableMetadata {
+    pub fn qualified_name(&self) -> QualifiedName {
+        QualifiedName::new(&self.schema, &self.name)
+    }
+
+    /// The columns keyed by name, for comparing two versions of a table without
+    /// depending on column order.
+    pub fn columns_by_name(&self) -> BTreeMap<&str, &ColumnMetadata> {
+        self.columns
+            .iter()
+            .map(|col| (col.name.as_str(), col))
+            .collect()
+    }
+
+    /// The primary key columns with their full metadata, in key order.
+    /// Empty when the table has no primary key.
+    pub fn primary_key(&self) -> Vec<&ColumnMetadata> {
+        self.primary_key_columns
+            .iter()
+            .filter_map(|pk| self.columns.iter().find(|col| &col.name == pk))
+            .collect()
+    }
+
+    /// Every set of columns that uniquely identifies a row, for upsert conflict targets.
+    /// The primary key comes first, followed by the unique indexes (which include the ones
+    /// backing `UNIQUE` constraints). Duplicate column sets are listed once.
+    pub fn unique_keys(&self) -> Vec<Vec<String>> {
+        let mut keys: Vec<Vec<String>> = Vec::new();
+        if !self.primary_key_columns.is_empty() {
+            keys.push(self.primary_key_columns.clone());
+        }
+        for index in self.indexes.iter().filter(|index| index.is_unique) {
+            if !keys.contains(&index.columns) {
+                keys.push(index.columns.clone());
+            }
+        }
+        keys
+    }
+
+    /// Heuristically decides whether this table only links two other tables: its primary key
+    /// is made up entirely of foreign-key columns that reference exactly two distinct tables,
+    /// and it has at most `JUNCTION_MAX_EXTRA_COLUMNS` other columns (e.g. `created_at`).
+    pub fn is_junction_table(&self) -> bool {
+        if self.primary_key_columns.is_empty() {
+            return false;
+        }
+        let primary_key = self.primary_key();
+        let pk_columns_are_fks = primary_key.len() == self.primary_key_columns.len()
+            && primary_key.iter().all(|col| col.foreign_key.is_some());
+        let extra_columns = self
+            .columns
+            .iter()
+            .filter(|col| !col.is_system && !col.is_primary_key)
+            .count();
+        pk_columns_are_fks
+            && extra_columns <= JUNCTION_MAX_EXTRA_COLUMNS
+            && self.junction_sides().len() == 2
+    }
+
+    /// One foreign-key edge per distinct table referenced by the primary key columns,
+    /// sorted by target. For composite foreign keys, the first key column stands in for it.
+    fn junction_sides(&self) -> Vec<Relationship> {
+        let mut sides: Vec<Relationship> = Vec::new();
+        for col in self
+            .columns
+            .iter()
+            .filter(|col| self.primary_key_columns.contains(&col.name))
+        {
+            let Some(fk) = &col.foreign_key else {
+                continue;
+            };
+            let seen = sides
+                .iter()
+                .any(|side| side.to_schema == fk.schema && side.to_table == fk.table);
+            if !seen {
+                sides.push(Relationship {
+                    from_schema: self.schema.clone(),
+                    from_table: self.name.clone(),
+                    from_column: col.name.clone(),
+                    to_schema: fk.schema.clone(),
+                    to_table: fk.table.clone(),
+                    to_column: fk.column.clone(),
+                });
+            }
+        }
+        sides.sort_by(|a, b| (&a.to_schema, &a.to_table).cmp(&(&b.to_schema, &b.to_table)));
+        sides
+    }
+}
+
 impl fmt::Display for TableMetadata {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         // Use bright_blue for the table name header
         writeln!(
             f,
             "{}",
-            format!("{}.{}", self.schema, self.name)
-                .bright_blue()
-                .bold()
+            self.qualified_name().to_string().bright_blue().bold()
         )?;
 
-        // Print columns
+        // Print columns, aligned to the widest name and types in this table
+        let widths = ColumnWidths::fit(&self.columns);
         for col in &self.columns {
-            writeln!(f, "{}", col)?;
+            writeln!(f, "{}", col.display_with(widths))?;
         }
         Ok(())
     }
@@ -267,6 +1515,22 @@ impl fmt::Debug for TableMetadata {
         writeln!(f, "Table '{}.{}':", self.schema, self.name)?;
         write_field!(f, "Primary Keys", &self.primary_key_columns)?;
         write_field!(f, "Comment", &self.comment)?;
+        write_field!(f, "Statistics", &self.statistics)?;
+        write_field!(f, "Partitioned", &self.is_partitioned)?;
+        if let Some(parent) = &self.partition_of {
+            write_field!(f, "Partition Of", parent)?;
+            write_field!(f, "Partition Bound", &self.partition_bound)?;
+        }
+        write_field!(f, "Triggers", &self.triggers, collection)?;
+        for trigger in &self.triggers {
+            writeln!(f, "{:#?}", trigger)?;
+        }
+        write_field!(f, "Indexes", &self.indexes)?;
+        write_field!(f, "RLS Enabled", &self.rls_enabled)?;
+        write_field!(f, "Policies", &self.policies, collection)?;
+        for policy in &self.policies {
+            writeln!(f, "{:#?}", policy)?;
+        }
         writeln!(f, "  Columns ({}):", self.columns.len())?;
         for col in &self.columns {
             writeln!(f, "{:#?}", col)?;
@@ -275,13 +1539,42 @@ impl fmt::Debug for TableMetadata {
     }
 }
 
-#[derive(Clone, Serialize, Deserialize, Default)]
+/// Approximate storage statistics for a table.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "camel-case", serde(rename_all = "camelCase"))]
+pub struct TableStatistics {
+    /// The planner's row estimate (`-1` if the table has never been analyzed).
+    pub estimated_rows: i64,
+    /// Total on-disk size, including indexes and TOAST data.
+    pub total_bytes: i64,
+    /// On-disk size of all indexes on the table.
+    pub index_bytes: i64,
+}
+
+#[derive(Clone, Serialize, Deserialize, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "camel-case", serde(rename_all = "camelCase"))]
 pub struct ViewMetadata {
     pub name: String,
     pub schema: String,
     pub columns: Vec<ColumnMetadata>,
     pub definition: Option<String>,
     pub comment: Option<String>,
+    /// Whether `UPDATE`/`DELETE` can be run against the view.
+    #[serde(default)]
+    pub is_updatable: bool,
+    /// Whether `INSERT` can be run against the view.
+    #[serde(default)]
+    pub is_insertable: bool,
+    /// Columns that identify a row of the view, standing in for the primary key views
+    /// lack. Empty unless set through `IntrospectionOptionsConfig::view_primary_keys`
+    /// or `infer_view_primary_keys`.
+    #[serde(default)]
+    pub logical_primary_key: Vec<String>,
+}
+impl ViewMetadata {
+    pub fn qualified_name(&self) -> QualifiedName {
+        QualifiedName::new(&self.schema, &self.name)
+    }
 }
 // Views can use the same Display format as Tables
 impl fmt::Display for ViewMetadata {
@@ -290,13 +1583,12 @@ impl fmt::Display for ViewMetadata {
         writeln!(
             f,
             "{}",
-            format!("{}.{}", self.schema, self.name)
-                .bright_green()
-                .bold()
+            self.qualified_name().to_string().bright_green().bold()
         )?;
 
+        let widths = ColumnWidths::fit(&self.columns);
         for col in &self.columns {
-            writeln!(f, "{}", col)?;
+            writeln!(f, "{}", col.display_with(widths))?;
         }
         Ok(())
     }
@@ -305,6 +1597,9 @@ impl fmt::Debug for ViewMetadata {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         writeln!(f, "View '{}.{}':", self.schema, self.name)?;
         write_field!(f, "Comment", &self.comment)?;
+        write_field!(f, "Updatable", &self.is_updatable)?;
+        write_field!(f, "Insertable", &self.is_insertable)?;
+        write_field!(f, "Logical PK", &self.logical_primary_key)?;
         if let Some(def) = &self.definition {
             writeln!(
                 f,
@@ -320,12 +1615,54 @@ impl fmt::Debug for ViewMetadata {
     }
 }
 
-#[derive(Clone, Serialize, Deserialize, Default)]
+#[derive(Clone, Serialize, Deserialize, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "camel-case", serde(rename_all = "camelCase"))]
 pub struct EnumMetadata {
     pub name: String,
     pub schema: String,
+    /// The enum labels, in the database's sort order.
     pub values: Vec<String>,
     pub comment: Option<String>,
+    /// Whether `values` still match the order in which they were declared.
+    /// This becomes `false` once a label is added with `ADD VALUE ... BEFORE/AFTER`.
+    #[serde(default)]
+    pub is_declaration_order: bool,
+}
+impl EnumMetadata {
+    pub fn qualified_name(&self) -> QualifiedName {
+        QualifiedName::new(&self.schema, &self.name)
+    }
+
+    /// Builds the `CREATE TYPE ... AS ENUM` statement that recreates this enum.
+    pub fn to_create_sql(&self) -> String {
+        let values = self
+            .values
+            .iter()
+            .map(|v| quote_literal(v))
+            .collect::<Vec<_>>()
+            .join(", ");
+        format!(
+            "CREATE TYPE {} AS ENUM ({});",
+            self.qualified_name().quoted(),
+            values
+        )
+    }
+
+    /// Pairs every value with a PascalCase identifier for it, e.g. `in progress` with
+    /// `InProgress` and `2xl` with `_2xl`. Generated enums use the identifier as the
+    /// variant name and the original value as its serde rename. Identifiers follow the
+    /// same length limit and collision strategy as type names, applied in value order.
+    pub fn variants(&self, options: &CodegenOptions) -> DbResult<Vec<(String, String)>> {
+        let source = self.qualified_name();
+        let mut taken = HashSet::new();
+        self.values
+            .iter()
+            .map(|value| {
+                let ident = options.unique_ident(&source, &to_pascal_case(value), &mut taken)?;
+                Ok((value.clone(), ident))
+            })
+            .collect()
+    }
 }
 impl fmt::Display for EnumMetadata {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -342,12 +1679,122 @@ impl fmt::Debug for EnumMetadata {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         writeln!(f, "Enum '{}.{}':", self.schema, self.name)?;
         write_field!(f, "Values", &self.values)?;
+        write_field!(f, "Declaration Order", &self.is_declaration_order)?;
         write_field!(f, "Comment", &self.comment)
     }
 }
 
-// NOTE: Function-related structs are left with derived Debug for now,
-// as they are not yet implemented in the introspector.
+#[derive(Clone, Serialize, Deserialize, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "camel-case", serde(rename_all = "camelCase"))]
+pub struct CompositeTypeMetadata {
+    pub name: String,
+    pub schema: String,
+    /// The fields of the type, in declaration order.
+    pub fields: Vec<(String, AxionDataType)>,
+}
+impl CompositeTypeMetadata {
+    pub fn qualified_name(&self) -> QualifiedName {
+        QualifiedName::new(&self.schema, &self.name)
+    }
+}
+impl fmt::Display for CompositeTypeMetadata {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let fields = self
+            .fields
+            .iter()
+            .map(|(name, ty)| format!("{} {}", name, ty))
+            .collect::<Vec<_>>()
+            .join(", ");
+        write!(f, "{}.{} ({})", self.schema, self.name, fields)
+    }
+}
+impl fmt::Debug for CompositeTypeMetadata {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Composite Type '{}.{}':", self.schema, self.name)?;
+        write_field!(f, "Fields", &self.fields)
+    }
+}
+
+#[derive(Clone, Serialize, Deserialize, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "camel-case", serde(rename_all = "camelCase"))]
+pub struct TriggerMetadata {
+    pub name: String,
+    /// The table the trigger is attached to.
+    pub table: String,
+    /// `BEFORE`, `AFTER` or `INSTEAD OF`.
+    pub timing: String,
+    /// The events that fire the trigger, e.g. `INSERT`, `UPDATE`.
+    pub events: Vec<String>,
+    /// The schema-qualified name of the function the trigger executes.
+    pub function: String,
+    /// The full `CREATE TRIGGER` statement.
+    pub definition: String,
+}
+
+impl fmt::Display for TriggerMetadata {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} {} {} ON {} -> {}",
+            self.name.yellow(),
+            self.timing,
+            self.events.join(" OR "),
+            self.table,
+            self.function
+        )
+    }
+}
+
+impl fmt::Debug for TriggerMetadata {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Trigger '{}' on '{}':", self.name, self.table)?;
+        write_field!(f, "Timing", &self.timing)?;
+        write_field!(f, "Events", &self.events)?;
+        write_field!(f, "Function", &self.function)?;
+        write_field!(f, "Definition", &self.definition)
+    }
+}
+
+/// An index over plain table columns.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "camel-case", serde(rename_all = "camelCase"))]
+pub struct IndexMetadata {
+    pub name: String,
+    /// The key columns, in index order. `INCLUDE` columns are not listed.
+    pub columns: Vec<String>,
+    pub is_unique: bool,
+    /// Whether this index backs the primary key.
+    pub is_primary: bool,
+}
+
+/// A row-level security policy.
+#[derive(Clone, Serialize, Deserialize, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "camel-case", serde(rename_all = "camelCase"))]
+pub struct RlsPolicy {
+    pub name: String,
+    /// The table the policy is attached to.
+    pub table: String,
+    /// `ALL`, `SELECT`, `INSERT`, `UPDATE` or `DELETE`.
+    pub command: String,
+    /// The roles the policy applies to (`public` for everyone).
+    pub roles: Vec<String>,
+    /// The `USING` expression, checked against existing rows.
+    pub using_expr: Option<String>,
+    /// The `WITH CHECK` expression, checked against new rows.
+    pub check_expr: Option<String>,
+}
+
+impl fmt::Debug for RlsPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Policy '{}' on '{}':", self.name, self.table)?;
+        write_field!(f, "Command", &self.command)?;
+        write_field!(f, "Roles", &self.roles)?;
+        write_field!(f, "Using", &self.using_expr)?;
+        write_field!(f, "With Check", &self.check_expr)
+    }
+}
+
+// NOTE: Function-related structs are left with derived Debug for now.
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
 pub enum RoutineKind {
@@ -367,6 +1814,7 @@ pub enum ParameterMode {
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "camel-case", serde(rename_all = "camelCase"))]
 pub struct ParameterMetadata {
     pub name: String,
     pub sql_type_name: String,
@@ -375,7 +1823,8 @@ pub struct ParameterMetadata {
     pub has_default: bool,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "camel-case", serde(rename_all = "camelCase"))]
 pub struct FunctionMetadata {
     pub name: String,
     pub schema: String,
@@ -385,3 +1834,730 @@ pub struct FunctionMetadata {
     pub return_table: Option<Vec<ColumnMetadata>>,
     pub comment: Option<String>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn enum_variants_are_valid_unique_identifiers() {
+        let status = EnumMetadata {
+            name: "status".to_string(),
+            values: ["in progress", "in-progress", "2xl", "done", "Self", ""]
+                .map(String::from)
+                .to_vec(),
+            ..Default::default()
+        };
+        let variants: Vec<_> = status
+            .variants(&CodegenOptions::default())
+            .unwrap()
+            .into_iter()
+            .map(|(value, ident)| format!("{}={}", value, ident))
+            .collect();
+        assert_eq!(
+            variants,
+            [
+                "in progress=InProgress",
+                "in-progress=InProgress2",
+                "2xl=_2xl",
+                "done=Done",
+                "Self=Self_",
+                "=_",
+            ]
+        );
+
+        let strict = CodegenOptions {
+            on_collision: crate::codegen::CollisionStrategy::Error,
+            ..Default::default()
+        };
+        assert!(matches!(
+            status.variants(&strict),
+            Err(DbError::Ambiguous(_))
+        ));
+        let short = CodegenOptions {
+            max_ident_len: 3,
+            ..Default::default()
+        };
+        let idents: Vec<_> = status
+            .variants(&short)
+            .unwrap()
+            .into_iter()
+            .map(|(_, ident)| ident)
+            .collect();
+        assert_eq!(idents, ["InP", "In2", "_2x", "Don", "Sel", "_"]);
+    }
+
+    #[test]
+    fn rust_type_maps_widths_wrappers_and_nullability() {
+        let cases = [
+            (AxionDataType::Integer(16), "i16"),
+            (AxionDataType::Integer(32), "i32"),
+            (AxionDataType::Integer(64), "i64"),
+            (AxionDataType::Float(32), "f32"),
+            (AxionDataType::Float(64), "f64"),
+            (
+                AxionDataType::Array(Box::new(AxionDataType::Uuid)),
+                "Vec<uuid::Uuid>",
+            ),
+            (
+                AxionDataType::Domain {
+                    name: "positive_int".to_string(),
+                    base: Box::new(AxionDataType::Integer(32)),
+                },
+                "i32",
+            ),
+            (
+                AxionDataType::Unsupported("hstore".to_string()),
+                "serde_json::Value /* unsupported: hstore */",
+            ),
+        ];
+        for (axion_type, rust_type) in cases {
+            assert_eq!(axion_type.rust_type(false), rust_type, "{:?}", axion_type);
+        }
+        assert_eq!(AxionDataType::Integer(64).rust_type(true), "Option<i64>");
+        assert_eq!(
+            AxionDataType::Array(Box::new(AxionDataType::Text)).rust_type(true),
+            "Option<Vec<String>>"
+        );
+    }
+
+    #[test]
+    fn rust_type_names_user_types_like_the_generated_declarations() {
+        let two_factor = AxionDataType::Enum("2fa_method".to_string());
+        assert_eq!(two_factor.rust_type(false), "_2faMethod");
+
+        let metadata = crate::builder::MetadataBuilder::new()
+            .schema("auth")
+            .enum_type("user_data", &["a"])
+            .enum_type("2fa_method", &["totp"])
+            .schema("public")
+            .enum_type("user_data", &["b"])
+            .build();
+        let names = crate::codegen::CodegenOptions::default()
+            .type_names(&metadata)
+            .unwrap();
+        let status = AxionDataType::Array(Box::new(AxionDataType::Enum("user_data".to_string())));
+        assert_eq!(
+            status.resolved_rust_type(false, "auth", &names),
+            "Vec<UserData>"
+        );
+        assert_eq!(
+            status.resolved_rust_type(true, "public", &names),
+            "Option<Vec<UserData2>>"
+        );
+        // Not defined in `public`, but only one schema has it.
+        assert_eq!(
+            two_factor.resolved_rust_type(false, "public", &names),
+            "_2faMethod"
+        );
+    }
+
+    #[test]
+    fn axion_data_type_round_trips_through_display() {
+        let types = vec![
+            AxionDataType::Text,
+            AxionDataType::Integer(16),
+            AxionDataType::Integer(32),
+            AxionDataType::Integer(64),
+            AxionDataType::Float(32),
+            AxionDataType::Float(64),
+            AxionDataType::Numeric,
+            AxionDataType::Boolean,
+            AxionDataType::Timestamp,
+            AxionDataType::TimestampTz,
+            AxionDataType::Date,
+            AxionDataType::Time,
+            AxionDataType::Bytes,
+            AxionDataType::Uuid,
+            AxionDataType::Json,
+            AxionDataType::JsonB,
+            AxionDataType::Inet,
+            AxionDataType::Enum("order_status".to_string()),
+            AxionDataType::Array(Box::new(AxionDataType::Uuid)),
+            AxionDataType::Array(Box::new(AxionDataType::Array(Box::new(
+                AxionDataType::Integer(4),
+            )))),
+            AxionDataType::Array(Box::new(AxionDataType::Enum("mood".to_string()))),
+            AxionDataType::Domain {
+                name: "email".to_string(),
+                base: Box::new(AxionDataType::Text),
+            },
+            AxionDataType::Domain {
+                name: "tags".to_string(),
+                base: Box::new(AxionDataType::Array(Box::new(AxionDataType::Text))),
+            },
+            AxionDataType::Array(Box::new(AxionDataType::Domain {
+                name: "positive_int".to_string(),
+                base: Box::new(AxionDataType::Integer(4)),
+            })),
+            AxionDataType::Composite("address".to_string()),
+            AxionDataType::Array(Box::new(AxionDataType::Composite("address".to_string()))),
+            AxionDataType::Geometry {
+                srid: None,
+                subtype: None,
+            },
+            AxionDataType::Geometry {
+                srid: Some(4326),
+                subtype: Some("Point".to_string()),
+            },
+            AxionDataType::Geography {
+                srid: Some(4326),
+                subtype: None,
+            },
+            AxionDataType::Array(Box::new(AxionDataType::Geography {
+                srid: None,
+                subtype: Some("Polygon".to_string()),
+            })),
+            AxionDataType::Unsupported("tsvector".to_string()),
+        ];
+
+        for ty in types {
+            let parsed: AxionDataType = ty.to_string().parse().unwrap();
+            assert_eq!(parsed, ty, "round trip of '{}'", ty);
+        }
+    }
+
+    fn sample_table(schema: &str, name: &str) -> TableMetadata {
+        TableMetadata {
+            name: name.to_string(),
+            schema: schema.to_string(),
+            columns: vec![ColumnMetadata {
+                name: "id".to_string(),
+                sql_type_name: "integer".to_string(),
+                canonical_sql_type: "integer".to_string(),
+                axion_type: AxionDataType::Integer(4),
+                is_nullable: false,
+                is_primary_key: true,
+                is_auto_increment: false,
+                default_value: None,
+                collation: None,
+                comment: None,
+                foreign_key: None,
+                is_system: false,
+            }],
+            primary_key_columns: vec!["id".to_string()],
+            ..Default::default()
+        }
+    }
+
+    fn sample_metadata(schema_order: &[&str], table_order: &[&str]) -> DatabaseMetadata {
+        let mut metadata = DatabaseMetadata::default();
+        for schema in schema_order {
+            let mut schema_md = SchemaMetadata {
+                name: schema.to_string(),
+                ..Default::default()
+            };
+            for table in table_order {
+                schema_md
+                    .tables
+                    .insert(table.to_string(), sample_table(schema, table));
+            }
+            metadata.schemas.insert(schema.to_string(), schema_md);
+        }
+        metadata
+    }
+
+    #[test]
+    fn snapshots_without_newer_fields_still_deserialize() {
+        let metadata = crate::builder::MetadataBuilder::new()
+            .table("users", |t| t.column("id", AxionDataType::Uuid).pk("id"))
+            .view("active_users", |v| v.column("id", AxionDataType::Uuid))
+            .enum_type("mood", &["happy", "sad"])
+            .build();
+
+        // Drop every field added after the first release, as an old export would lack them.
+        fn strip(value: &mut serde_json::Value) {
+            const ADDED: &[&str] = &[
+                "composite_types",
+                "compositeTypes",
+                "is_partitioned",
+                "isPartitioned",
+                "triggers",
+                "indexes",
+                "rls_enabled",
+                "rlsEnabled",
+                "policies",
+                "is_updatable",
+                "isUpdatable",
+                "is_insertable",
+                "isInsertable",
+                "is_declaration_order",
+                "isDeclarationOrder",
+            ];
+            match value {
+                serde_json::Value::Object(object) => {
+                    object.retain(|key, _| !ADDED.contains(&key.as_str()));
+                    object.values_mut().for_each(strip);
+                }
+                serde_json::Value::Array(items) => items.iter_mut().for_each(strip),
+                _ => {}
+            }
+        }
+        let mut old = serde_json::to_value(&metadata).unwrap();
+        strip(&mut old);
+        let mut expected = metadata;
+        // Without the flag, nothing says the labels are in declaration order.
+        for schema in expected.schemas.values_mut() {
+            for enum_meta in schema.enums.values_mut() {
+                enum_meta.is_declaration_order = false;
+            }
+        }
+        assert_eq!(
+            serde_json::from_value::<DatabaseMetadata>(old).unwrap(),
+            expected
+        );
+    }
+
+    #[test]
+    fn content_hash_ignores_insertion_order() {
+        let a = sample_metadata(
+            &["public", "auth", "billing"],
+            &["users", "orders", "items"],
+        );
+        let b = sample_metadata(
+            &["billing", "public", "auth"],
+            &["items", "users", "orders"],
+        );
+        assert_eq!(a, b);
+        assert_eq!(a.content_hash(), b.content_hash());
+    }
+
+    #[test]
+    fn content_hash_detects_schema_changes() {
+        let original = sample_metadata(&["public"], &["users"]);
+        let mut changed = original.clone();
+        let users = changed
+            .schemas
+            .get_mut("public")
+            .and_then(|s| s.tables.get_mut("users"))
+            .unwrap();
+        users.columns[0].is_nullable = true;
+        assert_ne!(original.content_hash(), changed.content_hash());
+
+        // Statistics alone are not a schema change
+        let mut with_stats = original.clone();
+        let users = with_stats
+            .schemas
+            .get_mut("public")
+            .and_then(|s| s.tables.get_mut("users"))
+            .unwrap();
+        users.statistics = Some(TableStatistics {
+            estimated_rows: 10,
+            total_bytes: 8192,
+            index_bytes: 4096,
+        });
+        assert_eq!(original.content_hash(), with_stats.content_hash());
+
+        // Neither is the server the schema was read from
+        let mut on_replica = original.clone();
+        on_replica.server_info = Some(ServerInfo {
+            version: "PostgreSQL 16.4".to_string(),
+            version_num: 160004,
+            server_encoding: "UTF8".to_string(),
+            database_name: "replica".to_string(),
+        });
+        assert_eq!(original.content_hash(), on_replica.content_hash());
+    }
+
+    #[test]
+    fn iterators_visit_every_entity() {
+        let metadata = sample_metadata(&["public", "auth"], &["users", "orders"]);
+        assert_eq!(metadata.all_tables().count(), 4);
+        assert_eq!(metadata.all_views().count(), 0);
+        assert_eq!(metadata.all_enums().count(), 0);
+
+        let mut columns: Vec<_> = metadata
+            .all_columns()
+            .map(|(schema, table, col)| format!("{}.{}.{}", schema, table, col.name))
+            .collect();
+        columns.sort();
+        assert_eq!(
+            columns,
+            [
+                "auth.orders.id",
+                "auth.users.id",
+                "public.orders.id",
+                "public.users.id"
+            ]
+        );
+    }
+
+    #[test]
+    fn unique_keys_combine_primary_key_and_unique_indexes() {
+        let mut table = sample_table("public", "users");
+        table.indexes = vec![
+            IndexMetadata {
+                name: "users_email_key".to_string(),
+                columns: vec!["email".to_string()],
+                is_unique: true,
+                is_primary: false,
+            },
+            IndexMetadata {
+                name: "users_name_idx".to_string(),
+                columns: vec!["name".to_string()],
+                is_unique: false,
+                is_primary: false,
+            },
+            IndexMetadata {
+                name: "users_pkey".to_string(),
+                columns: vec!["id".to_string()],
+                is_unique: true,
+                is_primary: true,
+            },
+        ];
+        assert_eq!(table.unique_keys(), [vec!["id"], vec!["email"]]);
+    }
+
+    #[test]
+    fn markdown_docs_note_empty_schemas() {
+        let mut metadata = DatabaseMetadata::default();
+        assert_eq!(
+            metadata.to_markdown_docs(),
+            "# Data Dictionary\n\n_No user schemas found._\n"
+        );
+
+        metadata.schemas.insert(
+            "public".to_string(),
+            SchemaMetadata {
+                name: "public".to_string(),
+                ..Default::default()
+            },
+        );
+        assert_eq!(
+            metadata.to_markdown_docs(),
+            "# Data Dictionary\n\n## Schema `public`\n\n_No tables or views._\n"
+        );
+    }
+
+    #[test]
+    fn markdown_docs_list_columns_with_placeholders_for_missing_comments() {
+        let metadata = crate::builder::MetadataBuilder::new()
+            .schema("public")
+            .table("users", |t| {
+                t.column("id", AxionDataType::Integer(64))
+                    .pk("id")
+                    .comment("Registered | active users")
+            })
+            .table("posts", |t| {
+                t.column("id", AxionDataType::Integer(64))
+                    .column("author_id", AxionDataType::Integer(64))
+                    .pk("id")
+                    .not_null("author_id")
+                    .foreign_key("author_id", "public", "users", "id")
+            })
+            .build();
+
+        let docs = metadata.to_markdown_docs();
+        let expected = "\
+# Data Dictionary
+
+## Schema `public`
+
+### `public.posts`
+
+—
+
+| Column | Type | Nullable | Key | Comment |
+| --- | --- | --- | --- | --- |
+| `id` | `int64` | No | PK | — |
+| `author_id` | `int64` | No | FK → `public.users.id` | — |
+
+### `public.users`
+
+Registered \\| active users
+
+| Column | Type | Nullable | Key | Comment |
+| --- | --- | --- | --- | --- |
+| `id` | `int64` | No | PK | — |
+";
+        assert_eq!(docs, expected);
+    }
+
+    #[test]
+    fn primary_key_resolves_columns_in_key_order() {
+        let metadata = crate::builder::MetadataBuilder::new()
+            .schema("public")
+            .table("memberships", |t| {
+                t.column("role", AxionDataType::Text)
+                    .column("user_id", AxionDataType::Uuid)
+                    .column("org_id", AxionDataType::Integer(64))
+                    .pk("org_id")
+                    .pk("user_id")
+            })
+            .build();
+        let table = &metadata.schemas["public"].tables["memberships"];
+
+        let key: Vec<_> = table
+            .primary_key()
+            .iter()
+            .map(|col| (col.name.as_str(), &col.axion_type))
+            .collect();
+        assert_eq!(
+            key,
+            [
+                ("org_id", &AxionDataType::Integer(64)),
+                ("user_id", &AxionDataType::Uuid)
+            ]
+        );
+    }
+
+    #[test]
+    fn junction_tables_yield_many_to_many_relationships() {
+        let metadata = crate::builder::MetadataBuilder::new()
+            .table("users", |t| t.column("id", AxionDataType::Uuid).pk("id"))
+            .table("roles", |t| t.column("id", AxionDataType::Uuid).pk("id"))
+            .table("user_roles", |t| {
+                t.column("user_id", AxionDataType::Uuid)
+                    .column("role_id", AxionDataType::Uuid)
+                    .column("granted_at", AxionDataType::Timestamp)
+                    .pk("user_id")
+                    .pk("role_id")
+                    .foreign_key("user_id", "public", "users", "id")
+                    .foreign_key("role_id", "public", "roles", "id")
+            })
+            .table("posts", |t| {
+                t.column("id", AxionDataType::Uuid)
+                    .column("author_id", AxionDataType::Uuid)
+                    .pk("id")
+                    .foreign_key("author_id", "public", "users", "id")
+            })
+            .build();
+
+        let tables = &metadata.schemas["public"].tables;
+        assert!(tables["user_roles"].is_junction_table());
+        assert!(!tables["posts"].is_junction_table());
+        assert!(!tables["users"].is_junction_table());
+
+        let links = metadata.many_to_many_relationships();
+        assert_eq!(links.len(), 1);
+        assert_eq!(
+            links[0].to_string(),
+            "public.roles <-> public.users via public.user_roles"
+        );
+    }
+
+    #[test]
+    fn spatial_modifiers_come_from_the_full_sql_type() {
+        let geometry = AxionDataType::Geometry {
+            srid: None,
+            subtype: None,
+        };
+        let point = geometry
+            .clone()
+            .with_spatial_modifiers("geometry(Point,4326)");
+        assert_eq!(point.to_string(), "GEOMETRY(Point,4326)");
+        let any_srid = geometry
+            .clone()
+            .with_spatial_modifiers("geometry(Geometry,3857)");
+        assert_eq!(
+            any_srid,
+            AxionDataType::Geometry {
+                srid: Some(3857),
+                subtype: None,
+            }
+        );
+        assert_eq!(any_srid.to_string(), "GEOMETRY(Geometry,3857)");
+        assert_eq!(
+            geometry.clone().with_spatial_modifiers("geometry"),
+            geometry
+        );
+        assert_eq!(
+            AxionDataType::Array(Box::new(geometry))
+                .with_spatial_modifiers("geometry(LineString)[]")
+                .to_string(),
+            "GEOMETRY(LineString)[]"
+        );
+        assert_eq!(
+            AxionDataType::Text.with_spatial_modifiers("character varying(20)"),
+            AxionDataType::Text
+        );
+    }
+
+    #[test]
+    fn dangling_relationships_point_outside_the_metadata() {
+        let metadata = crate::builder::MetadataBuilder::new()
+            .table("users", |t| t.column("id", AxionDataType::Uuid).pk("id"))
+            .table("posts", |t| {
+                t.column("author_id", AxionDataType::Uuid)
+                    .column("tenant_id", AxionDataType::Uuid)
+                    .column("editor_id", AxionDataType::Uuid)
+                    .foreign_key("author_id", "public", "users", "id")
+                    .foreign_key("tenant_id", "billing", "tenants", "id")
+                    .foreign_key("editor_id", "public", "editors", "id")
+            })
+            .build();
+
+        let dangling: Vec<_> = metadata
+            .dangling_relationships()
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+        assert_eq!(
+            dangling,
+            [
+                "public.posts.editor_id -> public.editors.id",
+                "public.posts.tenant_id -> billing.tenants.id",
+            ]
+        );
+    }
+
+    #[test]
+    fn case_insensitive_lookups_prefer_exact_matches() {
+        let metadata = sample_metadata(&["public"], &["users", "Orders", "orders", "ORDERS"]);
+        let public = metadata.schema_ci("PUBLIC").unwrap();
+        assert_eq!(public.table_ci("Users").unwrap().name, "users");
+        assert_eq!(public.table_ci("Orders").unwrap().name, "Orders");
+        assert!(matches!(
+            public.table_ci("oRdErS"),
+            Err(DbError::Ambiguous(_))
+        ));
+        assert!(matches!(
+            public.table_ci("missing"),
+            Err(DbError::NotFound(_))
+        ));
+        assert!(matches!(
+            metadata.schema_ci("auth"),
+            Err(DbError::NotFound(_))
+        ));
+    }
+
+    #[test]
+    fn classifies_type_changes() {
+        use AxionDataType::*;
+        let domain = |name: &str, base: AxionDataType| Domain {
+            name: name.to_string(),
+            base: Box::new(base),
+        };
+        let cases = [
+            (Text, Text, TypeChange::Identical),
+            (Integer(32), Integer(64), TypeChange::Widening),
+            (Integer(64), Integer(16), TypeChange::Narrowing),
+            (Float(32), Float(64), TypeChange::Widening),
+            (Integer(64), Numeric, TypeChange::Widening),
+            (Numeric, Integer(64), TypeChange::Narrowing),
+            (Text, Integer(32), TypeChange::Incompatible),
+            (Integer(32), Text, TypeChange::Incompatible),
+            (
+                Array(Box::new(Integer(16))),
+                Array(Box::new(Integer(32))),
+                TypeChange::Widening,
+            ),
+            (domain("email", Text), Text, TypeChange::Widening),
+            (Text, domain("email", Text), TypeChange::Narrowing),
+            (
+                domain("email", Text),
+                domain("email", Text),
+                TypeChange::Identical,
+            ),
+            (
+                domain("email", Text),
+                domain("url", Text),
+                TypeChange::Narrowing,
+            ),
+        ];
+        for (from, to, expected) in cases {
+            assert_eq!(from.compatibility(&to), expected, "{} -> {}", from, to);
+        }
+        assert!(!TypeChange::Widening.is_breaking());
+        assert!(TypeChange::Narrowing.is_breaking());
+    }
+
+    #[test]
+    fn column_type_changes_skip_unchanged_and_missing_columns() {
+        let before = crate::builder::MetadataBuilder::new()
+            .table("users", |t| {
+                t.column("id", AxionDataType::Integer(32))
+                    .column("age", AxionDataType::Integer(16))
+                    .column("name", AxionDataType::Text)
+                    .column("nickname", AxionDataType::Text)
+            })
+            .build();
+        let after = crate::builder::MetadataBuilder::new()
+            .table("users", |t| {
+                t.column("id", AxionDataType::Integer(64))
+                    .column("age", AxionDataType::Text)
+                    .column("name", AxionDataType::Text)
+            })
+            .build();
+
+        let changes: Vec<String> = before
+            .column_type_changes(&after)
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+        assert_eq!(
+            changes,
+            [
+                "public.users.age: INT16 -> TEXT (incompatible)",
+                "public.users.id: INT32 -> INT64 (widening)",
+            ]
+        );
+    }
+
+    #[test]
+    fn column_type_changes_ignore_column_order() {
+        let before = crate::builder::MetadataBuilder::new()
+            .table("users", |t| {
+                t.column("id", AxionDataType::Integer(32))
+                    .column("name", AxionDataType::Text)
+            })
+            .build();
+        let after = crate::builder::MetadataBuilder::new()
+            .table("users", |t| {
+                t.column("id", AxionDataType::Integer(32))
+                    .column("email", AxionDataType::Text)
+                    .column("name", AxionDataType::Text)
+            })
+            .build();
+        assert!(before.column_type_changes(&after).is_empty());
+
+        let columns = after.schemas["public"].tables["users"].columns_by_name();
+        let names: Vec<_> = columns.keys().copied().collect();
+        assert_eq!(names, ["email", "id", "name"]);
+        assert_eq!(columns["email"].axion_type, AxionDataType::Text);
+    }
+
+    #[test]
+    fn qualified_name_round_trips_through_display() {
+        let names = [
+            QualifiedName::new("public", "users"),
+            QualifiedName::new("public", "User Data"),
+            QualifiedName::new("tenant.eu", "orders"),
+            QualifiedName::new("public", "say \"hi\""),
+        ];
+        for name in names {
+            let parsed: QualifiedName = name.to_string().parse().unwrap();
+            assert_eq!(parsed, name);
+        }
+
+        let parsed: QualifiedName = "\"my.schema\".\"camelCase\"".parse().unwrap();
+        assert_eq!(parsed, QualifiedName::new("my.schema", "camelCase"));
+        assert_eq!(parsed.quoted(), "\"my.schema\".\"camelCase\"");
+
+        for bad in ["users", "public.", ".users", "\"public.users"] {
+            assert!(bad.parse::<QualifiedName>().is_err(), "{}", bad);
+        }
+    }
+
+    #[test]
+    fn field_casing_follows_the_camel_case_feature() {
+        let table = sample_table("public", "user_accounts");
+        let value = serde_json::to_value(&table).unwrap();
+        let key = if cfg!(feature = "camel-case") {
+            "primaryKeyColumns"
+        } else {
+            "primary_key_columns"
+        };
+        assert!(value.get(key).is_some(), "{}", value);
+
+        let round_trip: TableMetadata = serde_json::from_value(value).unwrap();
+        assert_eq!(round_trip.name, "user_accounts");
+    }
+
+    #[test]
+    fn axion_data_type_rejects_malformed_input() {
+        assert!("".parse::<AxionDataType>().is_err());
+        assert!("(INT4)".parse::<AxionDataType>().is_err());
+        assert!("INT4]".parse::<AxionDataType>().is_err());
+    }
+}
@@ -0,0 +1,162 @@
+// axion-db/src/query.rs
+use crate::{
+    error::{DbError, DbResult},
+    manager::{ModelManager, text_to_json},
+    metadata::{AxionDataType, ColumnMetadata, TableMetadata},
+    sql::{Dialect, JSON_PATH_SEPARATOR, json_path_text},
+};
+use sqlx::Row;
+use tracing::debug;
+
+/// A small builder for `SELECT` queries against an introspected table.
+///
+/// Every column name is validated against the table's `TableMetadata` before the
+/// query is built, and all filter values are sent as bound parameters.
+/// Created with `ModelManager::select`.
+pub struct SelectBuilder<'a> {
+    manager: &'a ModelManager,
+    schema: String,
+    table: String,
+    columns: Vec<String>,
+    filters: Vec<(String, String)>,
+    limit: Option<u64>,
+}
+
+impl<'a> SelectBuilder<'a> {
+    pub(crate) fn new(manager: &'a ModelManager, schema: &str, table: &str) -> Self {
+        Self {
+            manager,
+            schema: schema.to_string(),
+            table: table.to_string(),
+            columns: Vec::new(),
+            filters: Vec::new(),
+            limit: None,
+        }
+    }
+
+    /// Restricts the result to these columns. All columns are returned if never called.
+    pub fn columns(mut self, columns: &[&str]) -> Self {
+        self.columns = columns.iter().map(|c| c.to_string()).collect();
+        self
+    }
+
+    /// Adds a `column = value` condition. Conditions are combined with `AND`.
+    /// The value is given in the column's text form and cast to the column's type, so it
+    /// works for any column type and indexes on the column can still be used.
+    /// On Postgres `json` and `jsonb` columns, `column__key` compares the value of a
+    /// top-level key as text, and `column__a__b` follows nested objects.
+    pub fn filter_eq(mut self, column: &str, value: impl ToString) -> Self {
+        self.filters.push((column.to_string(), value.to_string()));
+        self
+    }
+
+    pub fn limit(mut self, limit: u64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Runs the query and returns each row as a JSON object keyed by column name.
+    /// Values are converted according to each column's `AxionDataType`.
+    pub async fn fetch_json(self) -> DbResult<Vec<serde_json::Value>> {
+        let table = self.manager.get_table(&self.schema, &self.table)?;
+        let find_column = |name: &str| -> DbResult<&ColumnMetadata> {
+            table
+                .columns
+                .iter()
+                .find(|c| c.name == name)
+                .ok_or_else(|| {
+                    DbError::InvalidInput(format!(
+                        "Column '{}' does not exist on table '{}.{}'",
+                        name, self.schema, self.table
+                    ))
+                })
+        };
+
+        let selected: Vec<&ColumnMetadata> = if self.columns.is_empty() {
+            table.columns.iter().collect()
+        } else {
+            self.columns
+                .iter()
+                .map(|name| find_column(name))
+                .collect::<DbResult<_>>()?
+        };
+        let dialect = self.manager.dialect();
+        let conditions = self
+            .filters
+            .iter()
+            .enumerate()
+            .map(|(i, (column, _))| match find_column(column) {
+                Ok(col) => Ok(dialect.eq_param(
+                    &dialect.quote_ident(&col.name),
+                    col.comparison_sql_type(),
+                    i + 1,
+                )),
+                Err(e) => json_filter_target(&table, column, dialect)
+                    .map(|target| format!("{} = {}", target, dialect.placeholder(i + 1)))
+                    .ok_or(e),
+            })
+            .collect::<DbResult<Vec<_>>>()?;
+
+        let mut sql = format!(
+            "SELECT {} FROM {}.{}",
+            selected
+                .iter()
+                .map(|col| {
+                    let name = dialect.quote_ident(&col.name);
+                    format!("{} AS {}", dialect.cast_to_text(&name), name)
+                })
+                .collect::<Vec<_>>()
+                .join(", "),
+            dialect.quote_ident(&self.schema),
+            dialect.quote_ident(&self.table)
+        );
+        if !conditions.is_empty() {
+            sql.push_str(&format!(" WHERE {}", conditions.join(" AND ")));
+        }
+        if let Some(limit) = self.limit {
+            sql.push_str(&format!(" LIMIT {}", limit));
+        }
+        debug!("Executing: {}", sql);
+
+        let mut query = sqlx::query(&sql);
+        for (_, value) in &self.filters {
+            query = query.bind(value.as_str());
+        }
+        let rows = query
+            .fetch_all(&*self.manager.db_client.pool)
+            .await
+            .map_err(|e| DbError::query_execution(e, &self.manager.db_client.pool))?;
+
+        rows.iter()
+            .map(|row| {
+                let mut object = serde_json::Map::with_capacity(selected.len());
+                for (i, col) in selected.iter().enumerate() {
+                    let value: Option<String> = row.try_get(i).map_err(DbError::QueryExecution)?;
+                    object.insert(col.name.clone(), text_to_json(value, &col.axion_type));
+                }
+                Ok(serde_json::Value::Object(object))
+            })
+            .collect()
+    }
+}
+
+/// Resolves a `column__key__...` filter on a `json` or `jsonb` column to the text of the
+/// nested key, or `None` if `filter` does not name one. The path operators are
+/// Postgres-only, so other dialects never resolve one.
+fn json_filter_target(table: &TableMetadata, filter: &str, dialect: Dialect) -> Option<String> {
+    if dialect != Dialect::Postgres {
+        return None;
+    }
+    let mut parts = filter.split(JSON_PATH_SEPARATOR);
+    let column_name = parts.next()?;
+    let path: Vec<&str> = parts.collect();
+    if path.is_empty() || path.iter().any(|key| key.is_empty()) {
+        return None;
+    }
+    let column = table.columns.iter().find(|c| c.name == column_name)?;
+    matches!(
+        column.axion_type,
+        AxionDataType::Json | AxionDataType::JsonB
+    )
+    .then(|| json_path_text(&dialect.quote_ident(&column.name), &path))
+}
@@ -0,0 +1,147 @@
+// axion-db/src/sql.rs
+
+//! Small helpers for building SQL text safely.
+
+/// The SQL flavor generated text is written in.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Dialect {
+    #[default]
+    Postgres,
+    Mysql,
+    Sqlite,
+}
+
+impl Dialect {
+    /// Quotes an identifier for this dialect: `"name"` for Postgres and SQLite,
+    /// `` `name` `` for MySQL. Embedded quote characters are doubled.
+    ///
+    /// Identifiers are always quoted, so reserved words and mixed case survive as is.
+    pub fn quote_ident(&self, name: &str) -> String {
+        let quote = match self {
+            Dialect::Postgres | Dialect::Sqlite => '"',
+            Dialect::Mysql => '`',
+        };
+        let escaped = name.replace(quote, &format!("{quote}{quote}"));
+        format!("{quote}{escaped}{quote}")
+    }
+
+    /// The placeholder for the `n`th (1-based) bound parameter: `$n` for Postgres, and
+    /// `?` for MySQL and SQLite, which bind parameters in the order they appear.
+    pub fn placeholder(&self, n: usize) -> String {
+        match self {
+            Dialect::Postgres => format!("${}", n),
+            Dialect::Mysql | Dialect::Sqlite => "?".to_string(),
+        }
+    }
+
+    /// Casts `expr` to text: `expr::TEXT` for Postgres, `CAST(expr AS CHAR)` for MySQL
+    /// and `CAST(expr AS TEXT)` for SQLite.
+    pub fn cast_to_text(&self, expr: &str) -> String {
+        match self {
+            Dialect::Postgres => format!("{}::TEXT", expr),
+            Dialect::Mysql => format!("CAST({} AS CHAR)", expr),
+            Dialect::Sqlite => format!("CAST({} AS TEXT)", expr),
+        }
+    }
+
+    /// An equality condition between `column` (already quoted) and the `n`th text
+    /// parameter. Postgres casts the parameter to the column's type so that an index on
+    /// the column can still be used, `"id" = $1::bigint`, and compares the column's text
+    /// form when the type is unknown. MySQL and SQLite convert the text themselves.
+    pub fn eq_param(&self, column: &str, sql_type: &str, n: usize) -> String {
+        match self {
+            Dialect::Postgres if sql_type.is_empty() => {
+                format!("{} = {}", self.cast_to_text(column), self.placeholder(n))
+            }
+            Dialect::Postgres => format!("{} = {}::{}", column, self.placeholder(n), sql_type),
+            Dialect::Mysql | Dialect::Sqlite => format!("{} = {}", column, self.placeholder(n)),
+        }
+    }
+}
+
+/// Quotes a Postgres identifier, escaping any embedded double quotes.
+/// Shorthand for `Dialect::Postgres.quote_ident`.
+pub fn quote_ident(ident: &str) -> String {
+    Dialect::Postgres.quote_ident(ident)
+}
+
+/// Quotes a SQL string literal, escaping any embedded single quotes.
+pub fn quote_literal(value: &str) -> String {
+    format!("'{}'", value.replace('\'', "''"))
+}
+
+/// Separates a JSON column from the nested keys in a filter name, e.g. `metadata__theme`.
+pub const JSON_PATH_SEPARATOR: &str = "__";
+
+/// Builds a Postgres expression that reads a nested key of a `json` or `jsonb` column as
+/// text: `column -> 'a' -> 'b' ->> 'c'` for the path `["a", "b", "c"]`. `column` must
+/// already be quoted, and `path` must not be empty.
+pub fn json_path_text(column: &str, path: &[&str]) -> String {
+    let mut expression = column.to_string();
+    for (i, key) in path.iter().enumerate() {
+        let operator = if i + 1 == path.len() { "->>" } else { "->" };
+        expression.push_str(&format!(" {} {}", operator, quote_literal(key)));
+    }
+    expression
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn quote_ident_preserves_case_and_special_characters() {
+        assert_eq!(quote_ident("camelCase"), "\"camelCase\"");
+        assert_eq!(quote_ident("User Data"), "\"User Data\"");
+        assert_eq!(quote_ident("a.b"), "\"a.b\"");
+        assert_eq!(quote_ident("say \"hi\""), "\"say \"\"hi\"\"\"");
+    }
+
+    #[test]
+    fn dialects_quote_reserved_words_and_embedded_quotes() {
+        assert_eq!(Dialect::Postgres.quote_ident("select"), "\"select\"");
+        assert_eq!(Dialect::Sqlite.quote_ident("Order"), "\"Order\"");
+        assert_eq!(Dialect::Mysql.quote_ident("select"), "`select`");
+        assert_eq!(Dialect::Mysql.quote_ident("a`b\"c"), "`a``b\"c`");
+    }
+
+    #[test]
+    fn quote_literal_escapes_single_quotes() {
+        assert_eq!(quote_literal("it's"), "'it''s'");
+    }
+
+    #[test]
+    fn eq_param_casts_the_parameter_rather_than_the_column() {
+        let pg = Dialect::Postgres;
+        assert_eq!(pg.eq_param("\"id\"", "bigint", 1), "\"id\" = $1::bigint");
+        assert_eq!(
+            pg.eq_param("\"at\"", "timestamp with time zone", 2),
+            "\"at\" = $2::timestamp with time zone"
+        );
+        assert_eq!(pg.eq_param("\"x\"", "", 3), "\"x\"::TEXT = $3");
+    }
+
+    #[test]
+    fn mysql_and_sqlite_use_their_own_placeholders_and_casts() {
+        assert_eq!(Dialect::Mysql.eq_param("`id`", "bigint", 2), "`id` = ?");
+        assert_eq!(Dialect::Sqlite.eq_param("\"id\"", "", 1), "\"id\" = ?");
+        assert_eq!(Dialect::Mysql.cast_to_text("`id`"), "CAST(`id` AS CHAR)");
+        assert_eq!(
+            Dialect::Sqlite.cast_to_text("\"id\""),
+            "CAST(\"id\" AS TEXT)"
+        );
+        assert_eq!(Dialect::Postgres.placeholder(4), "$4");
+    }
+
+    #[test]
+    fn json_path_text_chains_arrows_and_reads_the_last_key_as_text() {
+        assert_eq!(
+            json_path_text("\"meta\"", &["theme"]),
+            "\"meta\" ->> 'theme'"
+        );
+        assert_eq!(
+            json_path_text("\"meta\"", &["a", "b's", "c"]),
+            "\"meta\" -> 'a' -> 'b''s' ->> 'c'"
+        );
+    }
+}
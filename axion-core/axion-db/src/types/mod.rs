@@ -1,10 +1,47 @@
 // axion-db/src/types/mod.rs
-use crate::metadata::AxionDataType;
+use crate::metadata::{AxionDataType, QualifiedName};
+use std::collections::HashSet;
 
+#[cfg(feature = "postgres")]
 pub mod postgres;
 
+/// The user-defined types known to exist in the database, by schema and name, so that
+/// enums and composites can be told apart from other user-defined types.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct UserDefinedTypes {
+    pub enums: HashSet<QualifiedName>,
+    pub composites: HashSet<QualifiedName>,
+}
+
+impl UserDefinedTypes {
+    /// Whether `name` is an enum in `schema`, or in any schema when `schema` is `None`.
+    pub fn is_enum(&self, schema: Option<&str>, name: &str) -> bool {
+        Self::contains(&self.enums, schema, name)
+    }
+
+    /// Whether `name` is a composite type in `schema`, or in any schema when `schema`
+    /// is `None`.
+    pub fn is_composite(&self, schema: Option<&str>, name: &str) -> bool {
+        Self::contains(&self.composites, schema, name)
+    }
+
+    fn contains(types: &HashSet<QualifiedName>, schema: Option<&str>, name: &str) -> bool {
+        match schema {
+            Some(schema) => types.contains(&QualifiedName::new(schema, name)),
+            None => types.iter().any(|udt| udt.name == name),
+        }
+    }
+}
+
 /// A trait for mapping database-specific type names to Axion's normalized data types.
 pub trait TypeMapper: Send + Sync {
-    /// Maps a SQL type name and an optional UDT name to an `AxionDataType`.
-    fn sql_to_axion(&self, sql_type: &str, udt_name: Option<&str>) -> AxionDataType;
+    /// Maps a SQL type name and an optional UDT (by schema and name) to an
+    /// `AxionDataType`. `user_types` holds the enum and composite types known to exist.
+    fn sql_to_axion(
+        &self,
+        sql_type: &str,
+        udt_schema: Option<&str>,
+        udt_name: Option<&str>,
+        user_types: &UserDefinedTypes,
+    ) -> AxionDataType;
 }
@@ -1,6 +1,6 @@
 // axion-db/src/types/postgres.rs
 use crate::metadata::AxionDataType;
-use crate::types::TypeMapper;
+use crate::types::{TypeMapper, UserDefinedTypes};
 
 #[derive(Debug, Default, Clone, Copy)]
 pub struct PostgresTypeMapper;
@@ -20,20 +20,50 @@ macro_rules! map_sql_to_axion {
 }
 
 impl TypeMapper for PostgresTypeMapper {
-    fn sql_to_axion(&self, sql_type: &str, udt_name: Option<&str>) -> AxionDataType {
+    fn sql_to_axion(
+        &self,
+        sql_type: &str,
+        udt_schema: Option<&str>,
+        udt_name: Option<&str>,
+        user_types: &UserDefinedTypes,
+    ) -> AxionDataType {
         // Handle Array types first, as they are a special case of `data_type`
         if sql_type == "ARRAY" {
             // For arrays, the element type is in the UDT name (e.g., "_int4", "_varchar").
             // We strip the leading underscore to get the base type.
             if let Some(udt) = udt_name.and_then(|u| u.strip_prefix('_')) {
-                return AxionDataType::Array(Box::new(self.sql_to_axion(udt, Some(udt))));
+                // The element type only comes with its name, so enum and composite elements
+                // have to be recognized by name to reach the user-defined branch below.
+                // An array type lives in the schema of its element type.
+                let element_type = if user_types.is_enum(udt_schema, udt)
+                    || user_types.is_composite(udt_schema, udt)
+                {
+                    "USER-DEFINED"
+                } else {
+                    udt
+                };
+                return AxionDataType::Array(Box::new(self.sql_to_axion(
+                    element_type,
+                    udt_schema,
+                    Some(udt),
+                    user_types,
+                )));
             }
         }
 
-        // Handle User-Defined types next (this is for custom enums)
+        // Handle User-Defined types next. Known enums and composites and the PostGIS
+        // types are mapped; ranges and other extension types are not supported yet.
         if sql_type == "USER-DEFINED" {
             if let Some(udt) = udt_name {
-                return AxionDataType::Enum(udt.to_string());
+                return if user_types.is_enum(udt_schema, udt) {
+                    AxionDataType::Enum(udt.to_string())
+                } else if user_types.is_composite(udt_schema, udt) {
+                    AxionDataType::Composite(udt.to_string())
+                } else if matches!(udt, "geometry" | "geography") {
+                    self.sql_to_axion(udt, None, None, user_types)
+                } else {
+                    AxionDataType::Unsupported(udt.to_string())
+                };
             }
         }
 
@@ -58,6 +88,105 @@ impl TypeMapper for PostgresTypeMapper {
             "json" => AxionDataType::Json,
             "jsonb" => AxionDataType::JsonB,
             "inet" | "cidr" => AxionDataType::Inet,
+            // The subtype and SRID are in the type modifier, which only the full column
+            // type carries (see `AxionDataType::with_spatial_modifiers`).
+            "geometry" => AxionDataType::Geometry { srid: None, subtype: None },
+            "geography" => AxionDataType::Geography { srid: None, subtype: None },
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::metadata::QualifiedName;
+
+    fn user_types() -> UserDefinedTypes {
+        UserDefinedTypes {
+            enums: [QualifiedName::new("public", "mood")].into(),
+            composites: [QualifiedName::new("public", "address")].into(),
+        }
+    }
+
+    #[test]
+    fn maps_array_element_types() {
+        let mapper = PostgresTypeMapper;
+        let cases = [
+            ("_int4", AxionDataType::Integer(32)),
+            ("_text", AxionDataType::Text),
+            ("_mood", AxionDataType::Enum("mood".to_string())),
+            ("_address", AxionDataType::Composite("address".to_string())),
+            ("_hstore", AxionDataType::Unsupported("hstore".to_string())),
+            (
+                "_geography",
+                AxionDataType::Geography {
+                    srid: None,
+                    subtype: None,
+                },
+            ),
+        ];
+        for (udt, element) in cases {
+            assert_eq!(
+                mapper.sql_to_axion("ARRAY", Some("public"), Some(udt), &user_types()),
+                AxionDataType::Array(Box::new(element)),
+                "{}",
+                udt
+            );
+        }
+    }
+
+    #[test]
+    fn maps_user_defined_types_only_when_known() {
+        let mapper = PostgresTypeMapper;
+        assert_eq!(
+            mapper.sql_to_axion("USER-DEFINED", Some("public"), Some("mood"), &user_types()),
+            AxionDataType::Enum("mood".to_string())
+        );
+        assert_eq!(
+            mapper.sql_to_axion(
+                "USER-DEFINED",
+                Some("public"),
+                Some("mood"),
+                &UserDefinedTypes::default()
+            ),
+            AxionDataType::Unsupported("mood".to_string())
+        );
+        assert_eq!(
+            mapper.sql_to_axion(
+                "USER-DEFINED",
+                Some("public"),
+                Some("geometry"),
+                &UserDefinedTypes::default()
+            ),
+            AxionDataType::Geometry {
+                srid: None,
+                subtype: None,
+            }
+        );
+    }
+
+    #[test]
+    fn looks_up_user_defined_types_in_their_schema() {
+        let mapper = PostgresTypeMapper;
+        let types = UserDefinedTypes {
+            enums: [QualifiedName::new("audit", "status")].into(),
+            composites: [QualifiedName::new("public", "status")].into(),
+        };
+        assert_eq!(
+            mapper.sql_to_axion("USER-DEFINED", Some("audit"), Some("status"), &types),
+            AxionDataType::Enum("status".to_string())
+        );
+        assert_eq!(
+            mapper.sql_to_axion("USER-DEFINED", Some("public"), Some("status"), &types),
+            AxionDataType::Composite("status".to_string())
+        );
+        assert_eq!(
+            mapper.sql_to_axion("ARRAY", Some("public"), Some("_status"), &types),
+            AxionDataType::Array(Box::new(AxionDataType::Composite("status".to_string())))
+        );
+        assert_eq!(
+            mapper.sql_to_axion("USER-DEFINED", Some("sales"), Some("status"), &types),
+            AxionDataType::Unsupported("status".to_string())
+        );
+    }
+}
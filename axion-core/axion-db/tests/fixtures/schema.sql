@@ -0,0 +1,54 @@
+-- Fixture schema for the introspection integration tests.
+-- Covers tables, a view, an enum, a foreign key, functions (one overloaded) and an aggregate.
+CREATE SCHEMA app;
+
+CREATE TYPE app.order_status AS ENUM ('pending', 'shipped', 'delivered');
+
+CREATE TABLE app.customers (
+    id BIGSERIAL PRIMARY KEY,
+    email TEXT NOT NULL UNIQUE,
+    name TEXT
+);
+COMMENT ON TABLE app.customers IS 'People who place orders';
+COMMENT ON COLUMN app.customers.email IS 'Login address';
+
+CREATE TABLE app.orders (
+    id BIGSERIAL PRIMARY KEY,
+    customer_id BIGINT NOT NULL REFERENCES app.customers (id) ON DELETE CASCADE,
+    status app.order_status NOT NULL DEFAULT 'pending',
+    total NUMERIC(10, 2),
+    tags TEXT[],
+    history app.order_status[] NOT NULL DEFAULT '{pending}'
+);
+
+-- The primary key lists its columns in a different order than the table.
+CREATE TABLE app.shipments (
+    parcel INTEGER NOT NULL,
+    order_id BIGINT NOT NULL,
+    shipped_at TIMESTAMPTZ,
+    PRIMARY KEY (order_id, parcel)
+);
+
+CREATE VIEW app.pending_orders AS
+    SELECT id, customer_id, total
+    FROM app.orders
+    WHERE status = 'pending';
+COMMENT ON VIEW app.pending_orders IS 'Orders that have not shipped yet';
+
+CREATE FUNCTION app.order_count(customer BIGINT) RETURNS BIGINT
+    LANGUAGE sql STABLE
+    AS $$ SELECT count(*) FROM app.orders WHERE customer_id = customer $$;
+
+CREATE FUNCTION app.discount(total NUMERIC, percent INTEGER DEFAULT 10) RETURNS NUMERIC
+    LANGUAGE sql IMMUTABLE
+    AS $$ SELECT total * (100 - percent) / 100 $$;
+
+CREATE FUNCTION app.discount(total INTEGER) RETURNS INTEGER
+    LANGUAGE sql IMMUTABLE
+    AS $$ SELECT total * 9 / 10 $$;
+
+CREATE AGGREGATE app.product(NUMERIC) (
+    SFUNC = numeric_mul,
+    STYPE = NUMERIC,
+    INITCOND = '1'
+);
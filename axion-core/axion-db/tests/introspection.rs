@@ -0,0 +1,350 @@
+// axion-db/tests/introspection.rs
+//! Introspects a fixture schema in a throwaway Postgres container.
+//!
+//! These tests need a running Docker daemon, so they are ignored by default.
+//! Run them with `cargo test -p axion-db --test introspection -- --ignored`.
+//! They run against Postgres 16 unless `POSTGRES_TAG` names another image tag,
+//! e.g. `POSTGRES_TAG=9.6-alpine`. See the crate README for which server versions
+//! each kind of metadata needs.
+#![cfg(feature = "postgres")]
+
+use axion_db::{introspection::postgres::PostgresIntrospector, prelude::*};
+use futures::TryStreamExt;
+use std::sync::Arc;
+use testcontainers_modules::{
+    postgres::Postgres,
+    testcontainers::{ContainerAsync, ImageExt, runners::AsyncRunner},
+};
+
+const FIXTURE: &str = include_str!("fixtures/schema.sql");
+const DEFAULT_POSTGRES_TAG: &str = "16-alpine";
+
+/// Starts Postgres with the fixture applied and introspects the `app` schema.
+/// The container is returned so it lives as long as the test that uses it.
+async fn introspect_fixture() -> (ContainerAsync<Postgres>, DatabaseMetadata) {
+    let (container, introspector) = fixture_introspector().await;
+    let metadata = introspector.introspect(&["app".to_string()]).await.unwrap();
+    (container, metadata)
+}
+
+/// Starts Postgres with the fixture applied and returns an introspector connected to it.
+async fn fixture_introspector() -> (ContainerAsync<Postgres>, Box<dyn Introspector>) {
+    let (container, config) = fixture_database().await;
+    let client = Arc::new(DbClient::new(config).await.unwrap());
+    (container, new_introspector(client).unwrap())
+}
+
+/// Starts Postgres with the fixture applied and returns a config that connects to it.
+async fn fixture_database() -> (ContainerAsync<Postgres>, DbConfig) {
+    sqlx::any::install_default_drivers();
+
+    let container = Postgres::default()
+        .with_init_sql(FIXTURE.to_string().into_bytes())
+        .with_tag(std::env::var("POSTGRES_TAG").unwrap_or_else(|_| DEFAULT_POSTGRES_TAG.into()))
+        .start()
+        .await
+        .expect("failed to start the Postgres container");
+    let config = DbConfig::new(DatabaseType::Postgres)
+        .host(container.get_host().await.unwrap().to_string())
+        .port(container.get_host_port_ipv4(5432).await.unwrap())
+        .username("postgres")
+        .password("postgres")
+        .database_name("postgres");
+    (container, config)
+}
+
+#[tokio::test]
+#[ignore = "needs Docker"]
+async fn introspects_tables_and_foreign_keys() {
+    let (_container, metadata) = introspect_fixture().await;
+    let schema = &metadata.schemas["app"];
+
+    let mut tables: Vec<_> = schema.tables.keys().map(String::as_str).collect();
+    tables.sort();
+    assert_eq!(tables, ["customers", "orders", "shipments"]);
+
+    let customers = &schema.tables["customers"];
+    assert_eq!(customers.primary_key_columns, ["id"]);
+    assert_eq!(
+        customers.comment.as_deref(),
+        Some("People who place orders")
+    );
+    assert_eq!(customers.unique_keys(), [vec!["id"], vec!["email"]]);
+    let email = customers
+        .columns
+        .iter()
+        .find(|c| c.name == "email")
+        .unwrap();
+    assert!(!email.is_nullable);
+    assert_eq!(email.axion_type, AxionDataType::Text);
+    assert_eq!(email.comment.as_deref(), Some("Login address"));
+
+    let shipments = &schema.tables["shipments"];
+    assert_eq!(shipments.primary_key_columns, ["order_id", "parcel"]);
+    let key: Vec<_> = shipments
+        .primary_key()
+        .iter()
+        .map(|c| c.name.as_str())
+        .collect();
+    assert_eq!(key, ["order_id", "parcel"]);
+
+    let orders = &schema.tables["orders"];
+    let names: Vec<_> = orders.columns.iter().map(|c| c.name.as_str()).collect();
+    assert_eq!(
+        names,
+        ["id", "customer_id", "status", "total", "tags", "history"]
+    );
+    let column = |name: &str| orders.columns.iter().find(|c| c.name == name).unwrap();
+    assert_eq!(column("id").axion_type, AxionDataType::Integer(64));
+    assert!(column("id").is_auto_increment);
+    assert!(!column("customer_id").is_auto_increment);
+    let customer_fk = column("customer_id").foreign_key.clone().unwrap();
+    assert_eq!(customer_fk.on_delete, ReferentialAction::Cascade);
+    assert_eq!(customer_fk.on_update, ReferentialAction::NoAction);
+    assert_eq!(
+        column("status").axion_type,
+        AxionDataType::Enum("order_status".to_string())
+    );
+    assert_eq!(column("total").axion_type, AxionDataType::Numeric);
+    assert_eq!(column("total").canonical_sql_type, "numeric(10,2)");
+    assert_eq!(
+        column("tags").axion_type,
+        AxionDataType::Array(Box::new(AxionDataType::Text))
+    );
+    assert_eq!(
+        column("history").axion_type,
+        AxionDataType::Array(Box::new(AxionDataType::Enum("order_status".to_string())))
+    );
+    let history_default = column("history").default_value.clone().unwrap();
+    assert!(
+        history_default.starts_with("'{pending}'"),
+        "{}",
+        history_default
+    );
+
+    let server_info = metadata.server_info.as_ref().unwrap();
+    assert!(!server_info.version.is_empty());
+    assert!(server_info.version_num >= 90000);
+    assert_eq!(server_info.server_encoding, "UTF8");
+    assert_eq!(server_info.database_name, "postgres");
+
+    let relationships: Vec<_> = metadata
+        .relationships()
+        .iter()
+        .map(ToString::to_string)
+        .collect();
+    assert_eq!(
+        relationships,
+        ["app.orders.customer_id -> app.customers.id"]
+    );
+}
+
+#[tokio::test]
+#[ignore = "needs Docker"]
+async fn introspects_views_and_enums() {
+    let (_container, metadata) = introspect_fixture().await;
+    let schema = &metadata.schemas["app"];
+
+    let view = &schema.views["pending_orders"];
+    let names: Vec<_> = view.columns.iter().map(|c| c.name.as_str()).collect();
+    assert_eq!(names, ["id", "customer_id", "total"]);
+    assert!(view.definition.as_deref().unwrap().contains("pending"));
+    assert!(view.is_updatable);
+    assert_eq!(
+        view.comment.as_deref(),
+        Some("Orders that have not shipped yet")
+    );
+
+    let status = &schema.enums["order_status"];
+    assert_eq!(status.values, ["pending", "shipped", "delivered"]);
+    assert!(status.is_declaration_order);
+
+    // The function must not be mistaken for a relation.
+    assert!(!schema.tables.contains_key("order_count"));
+    assert!(!schema.views.contains_key("order_count"));
+}
+
+#[tokio::test]
+#[ignore = "needs Docker"]
+async fn renders_markdown_docs_with_introspected_comments() {
+    let (_container, metadata) = introspect_fixture().await;
+    let docs = metadata.to_markdown_docs();
+
+    assert!(docs.contains("### `app.customers`\n\nPeople who place orders\n"));
+    assert!(docs.contains("| `email` | `text` | No |  | Login address |"));
+    assert!(docs.contains("### `app.pending_orders` (view)\n\nOrders that have not shipped yet\n"));
+    // Relations without a comment still get a placeholder.
+    assert!(docs.contains("### `app.orders`\n\n—\n"));
+}
+
+#[tokio::test]
+#[ignore = "needs Docker"]
+async fn introspects_routines_by_kind() {
+    let (_container, metadata) = introspect_fixture().await;
+    let functions = &metadata.schemas["app"].functions;
+
+    let order_count = &functions["order_count"];
+    assert_eq!(order_count.kind, Some(RoutineKind::Function));
+    assert_eq!(order_count.return_type, Some(AxionDataType::Integer(64)));
+    let parameters: Vec<_> = order_count
+        .parameters
+        .iter()
+        .map(|p| (p.name.as_str(), p.axion_type.clone(), p.has_default))
+        .collect();
+    assert_eq!(
+        parameters,
+        [("customer", AxionDataType::Integer(64), false)]
+    );
+
+    // Both overloads are kept, each keyed by its signature.
+    assert!(!functions.contains_key("discount"));
+    let discount = &functions["discount(numeric, integer)"];
+    assert_eq!(discount.name, "discount");
+    let defaults: Vec<_> = discount
+        .parameters
+        .iter()
+        .map(|p| (p.name.as_str(), p.has_default))
+        .collect();
+    assert_eq!(defaults, [("total", false), ("percent", true)]);
+    assert_eq!(
+        functions["discount(integer)"].return_type,
+        Some(AxionDataType::Integer(32))
+    );
+
+    let product = &functions["product"];
+    assert_eq!(product.kind, Some(RoutineKind::Aggregate));
+    assert_eq!(product.return_type, Some(AxionDataType::Numeric));
+    assert_eq!(functions.len(), 4);
+}
+
+#[tokio::test]
+#[ignore = "needs Docker"]
+async fn introspects_relationship_graph_only() {
+    let (_container, introspector) = fixture_introspector().await;
+    let metadata = introspector
+        .introspect_graph(&["app".to_string()])
+        .await
+        .unwrap();
+    let schema = &metadata.schemas["app"];
+    assert!(schema.views.is_empty());
+    assert!(schema.enums.is_empty());
+
+    let orders = &schema.tables["orders"];
+    let names: Vec<_> = orders.columns.iter().map(|c| c.name.as_str()).collect();
+    assert_eq!(
+        names,
+        ["id", "customer_id", "status", "total", "tags", "history"]
+    );
+    assert_eq!(orders.primary_key_columns, ["id"]);
+    assert!(orders.columns.iter().all(|c| c.default_value.is_none()));
+    assert_eq!(
+        schema.tables["shipments"].primary_key_columns,
+        ["order_id", "parcel"]
+    );
+    // Types are mapped exactly as the full introspection maps them.
+    let full = introspector.introspect(&["app".to_string()]).await.unwrap();
+    for (column, full_column) in orders
+        .columns
+        .iter()
+        .zip(&full.schemas["app"].tables["orders"].columns)
+    {
+        assert_eq!(column.axion_type, full_column.axion_type, "{}", column.name);
+        assert_eq!(
+            column.sql_type_name, full_column.sql_type_name,
+            "{}",
+            column.name
+        );
+    }
+    let column = |name: &str| orders.columns.iter().find(|c| c.name == name).unwrap();
+    assert_eq!(column("id").axion_type, AxionDataType::Integer(64));
+    assert_eq!(
+        column("status").axion_type,
+        AxionDataType::Enum("order_status".to_string())
+    );
+    assert_eq!(
+        column("tags").axion_type,
+        AxionDataType::Array(Box::new(AxionDataType::Text))
+    );
+    assert_eq!(
+        column("history").axion_type,
+        AxionDataType::Array(Box::new(AxionDataType::Enum("order_status".to_string())))
+    );
+    assert_eq!(schema.tables["customers"].comment, None);
+
+    let relationships: Vec<_> = metadata
+        .relationships()
+        .iter()
+        .map(ToString::to_string)
+        .collect();
+    assert_eq!(
+        relationships,
+        ["app.orders.customer_id -> app.customers.id"]
+    );
+}
+
+#[tokio::test]
+#[ignore = "needs Docker"]
+async fn query_json_decodes_by_runtime_type_and_metadata() {
+    let (_container, config) = fixture_database().await;
+    let manager = ModelManager::new(config).await.unwrap();
+    let customer_id: i64 = sqlx::query_scalar(
+        "INSERT INTO app.customers (email) VALUES ('a@example.com') RETURNING id",
+    )
+    .fetch_one(&*manager.db_client.pool)
+    .await
+    .unwrap();
+    sqlx::query("INSERT INTO app.orders (customer_id, total) VALUES ($1, 12.50)")
+        .bind(customer_id)
+        .execute(&*manager.db_client.pool)
+        .await
+        .unwrap();
+
+    let rows = manager
+        .query_json(
+            "SELECT id, customer_id = $1 AS mine, total::TEXT AS total, NULL::TEXT AS note \
+             FROM app.orders WHERE customer_id = $1",
+            &[serde_json::json!(customer_id)],
+        )
+        .await
+        .unwrap();
+    assert_eq!(
+        rows,
+        [serde_json::json!({
+            "id": 1,
+            "mine": true,
+            "total": "12.50",
+            "note": null,
+        })]
+    );
+}
+
+#[tokio::test]
+#[ignore = "needs Docker"]
+async fn survey_counts_entities_without_introspecting() {
+    let (_container, introspector) = fixture_introspector().await;
+    let report = introspector.survey(&["app".to_string()]).await.unwrap();
+
+    let app = report.per_schema["app"];
+    assert_eq!(
+        (app.tables, app.views, app.enums, app.functions),
+        (3, 1, 1, 4)
+    );
+    assert_eq!(report.totals(), app);
+}
+
+#[tokio::test]
+#[ignore = "needs Docker"]
+async fn streams_tables_as_they_are_introspected() {
+    let (_container, config) = fixture_database().await;
+    let introspector = PostgresIntrospector::new(Arc::new(DbClient::new(config).await.unwrap()));
+    let tables: Vec<TableMetadata> = introspector
+        .stream_tables("app")
+        .try_collect()
+        .await
+        .unwrap();
+
+    let names: Vec<_> = tables.iter().map(|t| t.name.as_str()).collect();
+    assert_eq!(names, ["customers", "orders", "shipments"]);
+    assert_eq!(tables[1].primary_key_columns, ["id"]);
+    assert_eq!(tables[2].primary_key_columns, ["order_id", "parcel"]);
+}
@@ -1,25 +1,9 @@
 // axion/examples/axion_serialize_schema.rs
-use axion_db::introspection::Introspector;
-use axion_db::{
-    client::DbClient, error::DbResult, introspection::postgres::PostgresIntrospector, prelude::*,
-};
+use axion_db::prelude::*;
 use std::{fs, io::Write, path::Path, sync::Arc};
 use tracing::{Level, info, span};
 use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
 
-// Helper function to list all user-defined schemas (same as before)
-async fn list_all_user_schemas(client: &DbClient) -> DbResult<Vec<String>> {
-    let query = "
-        SELECT nspname::TEXT AS schema_name
-        FROM pg_catalog.pg_namespace
-        WHERE nspname NOT IN ('information_schema', 'pg_catalog', 'pg_toast')
-          AND nspname NOT LIKE 'pg_temp_%'
-        ORDER BY schema_name;
-    ";
-    let rows: Vec<(String,)> = sqlx::query_as(query).fetch_all(&*client.pool).await?;
-    Ok(rows.into_iter().map(|r| r.0).collect())
-}
-
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
     // Using anyhow::Result for easier error handling with file I/O
@@ -49,7 +33,7 @@ async fn main() -> anyhow::Result<()> {
     info!("Starting database introspection and serialization...");
 
     // ---- Configuration ----
-    let db_config = DbConfig::new(axion_db::config::DatabaseType::Postgres)
+    let db_config = DbConfig::new(DatabaseType::Postgres)
         .host(std::env::var("DB_HOST").unwrap_or_else(|_| "localhost".into()))
         .port(
             std::env::var("DB_PORT")
@@ -62,8 +46,8 @@ async fn main() -> anyhow::Result<()> {
 
     // ---- Introspection ----
     let client = Arc::new(DbClient::new(db_config).await?);
-    let all_schemas = list_all_user_schemas(&client).await?;
-    let introspector = PostgresIntrospector::new(client.clone());
+    let introspector = new_introspector(client.clone())?;
+    let all_schemas = introspector.list_user_schemas().await?;
 
     let span = span!(Level::INFO, "introspect_and_serialize");
     let _enter = span.enter();
@@ -1,6 +1,6 @@
 // examples/server_simple.rs
 use axion::api::PrismApi;
-use axion::api::prism::PrismConfig;
+use axion::api::prism::{BindAddress, PrismConfig};
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
@@ -15,8 +15,9 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         version: env!("CARGO_PKG_VERSION"),
         description: Option::Some(env!("CARGO_PKG_DESCRIPTION")),
         static_assets_path: Some(std::path::PathBuf::from("assets")),
-        host: "127.0.0.1", // Explicit string conversion
-        port: 3000,
+        bind: BindAddress::Tcp("127.0.0.1".to_string(), 3000),
+        request_timeout_seconds: 30,
+        max_body_bytes: 2 * 1024 * 1024,
     };
 
     // Create PrismApi with our config
@@ -1,8 +1,10 @@
 // src/api/health/routes.rs
 
+use axion_db::prelude::{ModelManager, ModelStats, PoolStats};
 use axum::{
     Json, Router,
     extract::State,
+    http::StatusCode,
     routing::{get, post},
 };
 use chrono::{DateTime, Utc};
@@ -19,6 +21,8 @@ pub type SharedAppState = Arc<Mutex<AppState>>;
 pub struct AppState {
     pub start_time: SystemTime,
     pub database_connected: bool,
+    /// The manager holding the introspected schema, if one was attached.
+    pub model_manager: Option<ModelManager>,
 }
 
 // Health check response model
@@ -29,6 +33,18 @@ pub struct HealthResponse {
     version: String,
     uptime: f64,
     database_connected: bool,
+    /// Connection pool counters, present when a ModelManager is attached.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pool: Option<PoolStats>,
+}
+
+// Schema freshness response model
+#[derive(Serialize)]
+pub struct SchemaHealthResponse {
+    introspected_at: Option<String>,
+    introspection_seconds: f64,
+    stats: ModelStats,
+    content_hash: String,
 }
 
 // Cache status response model
@@ -64,9 +80,31 @@ async fn health_check(State(state): State<SharedAppState>) -> Json<HealthRespons
         version: env!("CARGO_PKG_VERSION").to_string(),
         uptime,
         database_connected: state.database_connected,
+        pool: state.model_manager.as_ref().map(ModelManager::pool_stats),
     })
 }
 
+// Handler reporting when the cached schema was introspected and what it contains
+async fn schema_health(
+    State(state): State<SharedAppState>,
+) -> Result<Json<SchemaHealthResponse>, StatusCode> {
+    // Clone the manager out so the lock is not held while hashing
+    let manager = state
+        .lock()
+        .unwrap()
+        .model_manager
+        .clone()
+        .ok_or(StatusCode::SERVICE_UNAVAILABLE)?;
+    let (metadata, report) = manager.metadata_and_report();
+
+    Ok(Json(SchemaHealthResponse {
+        introspected_at: report.finished_at.map(|t| t.to_rfc3339()),
+        introspection_seconds: report.total_duration.as_secs_f64(),
+        stats: metadata.stats(),
+        content_hash: format!("{:016x}", metadata.content_hash()),
+    }))
+}
+
 // Simple ping handler for load balancer health checks
 async fn ping() -> &'static str {
     "pong"
@@ -100,6 +138,7 @@ pub fn create_health_routes() -> Router<SharedAppState> {
     Router::new()
         .route("/", get(health_check))
         .route("/ping", get(ping))
+        .route("/schema", get(schema_health))
         .route("/cache", get(cache_status))
         .route("/clear-cache", post(clear_cache))
 }
@@ -0,0 +1,197 @@
+// src/api/metadata.rs
+
+use axion_db::prelude::{DatabaseMetadata, Relation, TableMetadata, ViewMetadata};
+use axum::{
+    Json, Router,
+    extract::{Query, State},
+    http::StatusCode,
+    routing::get,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::api::health::SharedAppState;
+
+/// Page size used when the request does not set `limit`
+pub const DEFAULT_PAGE_SIZE: usize = 100;
+/// Largest page a single request may ask for
+pub const MAX_PAGE_SIZE: usize = 1000;
+
+/// Query parameters accepted by `GET /dt`
+#[derive(Debug, Default, Deserialize)]
+pub struct MetadataQuery {
+    /// Only list entities in this schema
+    pub schema: Option<String>,
+    /// Only list entities whose name contains this text, ignoring case
+    pub table: Option<String>,
+    pub limit: Option<usize>,
+    pub offset: Option<usize>,
+    /// Comma-separated fields to include besides `schema`, `name` and `kind`,
+    /// e.g. `columns,primary_key`. Every field is included when omitted.
+    /// `primary_key` is accepted for `primary_key_columns`.
+    pub fields: Option<String>,
+}
+
+/// One page of tables and views, sorted by schema and name
+#[derive(Debug, Serialize)]
+pub struct MetadataPage {
+    /// Number of entities matching the filters, across all pages
+    pub total: usize,
+    pub offset: usize,
+    pub limit: usize,
+    pub items: Vec<Value>,
+}
+
+/// Filters, sorts, pages and projects the tables and views of `metadata`.
+/// Fails with a message when `fields` names something that is not a field.
+pub fn metadata_page(
+    metadata: &DatabaseMetadata,
+    query: &MetadataQuery,
+) -> Result<MetadataPage, String> {
+    let fields: Option<Vec<&str>> = query.fields.as_deref().map(|f| {
+        f.split(',')
+            .map(str::trim)
+            .filter(|f| !f.is_empty())
+            .map(field_name)
+            .collect()
+    });
+    if let Some(unknown) = fields.iter().flatten().find(|f| !is_known_field(f)) {
+        return Err(format!("Unknown field '{}'", unknown));
+    }
+
+    let name_filter = query.table.as_deref().map(str::to_lowercase);
+    let mut entities: Vec<Relation> = Vec::new();
+    for schema in metadata.schemas.values() {
+        if query.schema.as_deref().is_some_and(|s| s != schema.name) {
+            continue;
+        }
+        entities.extend(schema.relations().filter(|relation| {
+            name_filter
+                .as_deref()
+                .is_none_or(|filter| relation.name().to_lowercase().contains(filter))
+        }));
+    }
+    entities.sort_by_key(|relation| (relation.schema(), relation.name()));
+
+    // Only the requested page is serialized.
+    let total = entities.len();
+    let offset = query.offset.unwrap_or(0);
+    let limit = query.limit.unwrap_or(DEFAULT_PAGE_SIZE).min(MAX_PAGE_SIZE);
+    let items = entities
+        .into_iter()
+        .skip(offset)
+        .take(limit)
+        .map(|relation| {
+            let (kind, value) = match relation {
+                Relation::Table(table) => ("table", serde_json::to_value(table)),
+                Relation::View(view) => ("view", serde_json::to_value(view)),
+            };
+            value
+                .map(|value| project(value, kind, fields.as_deref()))
+                .map_err(|e| e.to_string())
+        })
+        .collect::<Result<_, _>>()?;
+
+    Ok(MetadataPage {
+        total,
+        offset,
+        limit,
+        items,
+    })
+}
+
+/// Keeps `schema`, `name` and the requested fields of a serialized entity (all of them
+/// when `fields` is `None`) and tags it with its kind.
+fn project(value: Value, kind: &str, fields: Option<&[&str]>) -> Value {
+    let mut object = match value {
+        Value::Object(object) => object,
+        other => return other,
+    };
+    if let Some(fields) = fields {
+        object = ["schema", "name"]
+            .iter()
+            .chain(fields)
+            .filter_map(|key| object.remove(*key).map(|field| (key.to_string(), field)))
+            .collect();
+    }
+    object.insert("kind".to_string(), Value::String(kind.to_string()));
+    Value::Object(object)
+}
+
+/// The serialized name of a requested field, resolving the short aliases.
+fn field_name(field: &str) -> &str {
+    match field {
+        "primary_key" => "primary_key_columns",
+        "primaryKey" => "primaryKeyColumns",
+        other => other,
+    }
+}
+
+/// Whether `key` is a field of serialized tables or views. Fields only one of them has
+/// are accepted, and simply left out of the entities that lack them.
+fn is_known_field(key: &str) -> bool {
+    let table = serde_json::to_value(TableMetadata::default());
+    let view = serde_json::to_value(ViewMetadata::default());
+    [table, view]
+        .iter()
+        .flatten()
+        .any(|value| value.get(key).is_some())
+}
+
+// Handler listing a page of tables and views
+async fn list_entities(
+    State(state): State<SharedAppState>,
+    Query(query): Query<MetadataQuery>,
+) -> Result<Json<MetadataPage>, (StatusCode, String)> {
+    // Clone the manager out so the lock is not held while serializing
+    let manager = state.lock().unwrap().model_manager.clone().ok_or((
+        StatusCode::SERVICE_UNAVAILABLE,
+        "No database metadata is loaded".to_string(),
+    ))?;
+
+    metadata_page(&manager.metadata(), &query)
+        .map(Json)
+        .map_err(|e| (StatusCode::BAD_REQUEST, e))
+}
+
+// Function to create the metadata routes router
+pub fn create_metadata_routes() -> Router<SharedAppState> {
+    Router::new().route("/", get(list_entities))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axion_db::prelude::{AxionDataType, MetadataBuilder};
+
+    #[test]
+    fn fields_accept_primary_key_for_primary_key_columns() {
+        let metadata = MetadataBuilder::new()
+            .schema("public")
+            .table("users", |t| {
+                t.column("id", AxionDataType::Integer(32))
+                    .column("email", AxionDataType::Text)
+                    .pk("id")
+            })
+            .build();
+        let query = MetadataQuery {
+            fields: Some("columns,primary_key".to_string()),
+            ..Default::default()
+        };
+
+        let page = metadata_page(&metadata, &query).unwrap();
+        let users = &page.items[0];
+        let primary_key = users
+            .get("primary_key_columns")
+            .or_else(|| users.get("primaryKeyColumns"));
+        assert_eq!(primary_key, Some(&serde_json::json!(["id"])));
+        assert_eq!(users["columns"].as_array().unwrap().len(), 2);
+        assert!(users.get("comment").is_none());
+
+        let query = MetadataQuery {
+            fields: Some("primary_keys".to_string()),
+            ..Default::default()
+        };
+        assert!(metadata_page(&metadata, &query).is_err());
+    }
+}
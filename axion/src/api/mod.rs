@@ -1,5 +1,8 @@
 pub mod health;
 pub use health::create_health_routes;
 
+pub mod metadata;
+pub use metadata::create_metadata_routes;
+
 pub mod prism;
 pub use prism::PrismApi;
@@ -1,19 +1,46 @@
 // src/api/prism.rs
 
-use axum::Router;
-use dev_utils::{debug, info};
+use axion_db::prelude::ModelManager;
+use axum::{Router, http::StatusCode};
+use dev_utils::{debug, info, warn};
 use std::{
+    fmt,
     net::{IpAddr, SocketAddr},
-    path::PathBuf,
+    path::{Path, PathBuf},
     str::FromStr,
     sync::{Arc, Mutex},
-    time::SystemTime,
+    time::{Duration, SystemTime},
 };
 use tokio::net::TcpListener;
+use tower_http::{limit::RequestBodyLimitLayer, services::ServeDir, timeout::TimeoutLayer};
 
 use crate::api::health::{AppState, SharedAppState};
 
-use super::create_health_routes;
+use super::{create_health_routes, create_metadata_routes};
+
+/// Default time a request may take before the server answers with `408 Request Timeout`
+pub const DEFAULT_REQUEST_TIMEOUT_SECONDS: u64 = 30;
+/// Default maximum accepted request body size (2 MB)
+pub const DEFAULT_MAX_BODY_BYTES: usize = 2 * 1024 * 1024;
+
+/// Where the server listens for connections
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BindAddress {
+    /// A host (name or IP) and port
+    Tcp(String, u16),
+    /// A Unix domain socket at this path, e.g. for a reverse proxy on the same machine.
+    /// Only supported on Unix platforms.
+    Unix(PathBuf),
+}
+
+impl fmt::Display for BindAddress {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BindAddress::Tcp(host, port) => write!(f, "http://{}:{}", host, port),
+            BindAddress::Unix(path) => write!(f, "unix:{}", path.display()),
+        }
+    }
+}
 
 /// Configuration options for PrismApi
 pub struct PrismConfig<S = String, P = PathBuf>
@@ -25,8 +52,9 @@ where
     pub version: S,
     pub description: Option<S>,
     pub static_assets_path: Option<P>,
-    pub host: S,
-    pub port: u16,
+    pub bind: BindAddress,
+    pub request_timeout_seconds: u64,
+    pub max_body_bytes: usize,
 }
 
 impl<S, P> PrismConfig<S, P>
@@ -34,7 +62,8 @@ where
     S: Into<String> + Clone,
     P: Into<PathBuf> + Clone,
 {
-    /// Create a new configuration with provided values
+    /// Create a new configuration listening on `host:port` over TCP.
+    /// Request timeout and body-size limits start at their defaults.
     pub fn new(
         project_name: S,
         version: S,
@@ -48,8 +77,9 @@ where
             version,
             description,
             static_assets_path,
-            host,
-            port,
+            bind: BindAddress::Tcp(host.into(), port),
+            request_timeout_seconds: DEFAULT_REQUEST_TIMEOUT_SECONDS,
+            max_body_bytes: DEFAULT_MAX_BODY_BYTES,
         }
     }
 
@@ -60,8 +90,9 @@ where
             version: self.version.into(),
             description: self.description.map(Into::into),
             static_assets_path: self.static_assets_path.map(Into::into),
-            host: self.host.into(),
-            port: self.port,
+            bind: self.bind,
+            request_timeout_seconds: self.request_timeout_seconds,
+            max_body_bytes: self.max_body_bytes,
         }
     }
 }
@@ -73,8 +104,9 @@ impl Default for PrismConfig<String, PathBuf> {
             version: env!("CARGO_PKG_VERSION").into(),
             description: None,
             static_assets_path: None,
-            host: "localhost".into(),
-            port: 8080,
+            bind: BindAddress::Tcp("localhost".into(), 8080),
+            request_timeout_seconds: DEFAULT_REQUEST_TIMEOUT_SECONDS,
+            max_body_bytes: DEFAULT_MAX_BODY_BYTES,
         }
     }
 }
@@ -97,6 +129,7 @@ impl Default for PrismApi {
             state: Arc::new(Mutex::new(AppState {
                 start_time: SystemTime::now(),
                 database_connected: true,
+                model_manager: None,
             })),
             // app: None,
         }
@@ -118,6 +151,7 @@ impl PrismApi {
         let state = Arc::new(Mutex::new(AppState {
             start_time: SystemTime::now(),
             database_connected: true, // In a real app, we'd check the database
+            model_manager: None,
         }));
 
         Self {
@@ -127,18 +161,32 @@ impl PrismApi {
         }
     }
 
+    /// Attach a ModelManager so the health routes can report on the introspected schema
+    pub fn with_model_manager(self, manager: ModelManager) -> Self {
+        self.state.lock().unwrap().model_manager = Some(manager);
+        self
+    }
+
     /// Print welcome message with server information
-    pub fn print_welcome(&self, host: &str, port: u16) {
+    pub fn print_welcome(&self, bind: &BindAddress) {
         info!("===========================================");
         info!("🚀 {} v{}", self.config.project_name, self.config.version);
         if let Some(desc) = &self.config.description {
             debug!("{}", desc);
         }
         info!("===========================================");
-        let address = format!("http://{}:{}", host, port);
+        // Routes on a socket have no URL prefix, so they are listed as bare paths
+        let address = match bind {
+            BindAddress::Tcp(..) => bind.to_string(),
+            BindAddress::Unix(_) => String::new(),
+        };
         info!("📚 API documentation: {address}/docs");
-        info!("📡 Server running at: {address}");
+        info!("📡 Server running at: {bind}");
         info!("🏥 Health status: {address}/health");
+        info!("🗃️ Metadata: {address}/dt");
+        if self.config.static_assets_path.is_some() {
+            info!("🗂️ Static assets: {address}/static");
+        }
 
         info!("===========================================");
     }
@@ -151,12 +199,31 @@ impl PrismApi {
     /// Build the complete application router with proper state handling
     pub fn build_router(&self) -> Router {
         // Create a router without explicit state type first
-        let router = Router::new()
-            // Nest health routes
-            .nest("/health", create_health_routes());
+        let mut router = Router::new()
+            // Nest health and metadata routes
+            .nest("/health", create_health_routes())
+            .nest("/dt", create_metadata_routes());
+
+        // Serve static assets when a path is configured and actually exists
+        if let Some(path) = &self.config.static_assets_path {
+            if path.is_dir() {
+                router = router.nest_service("/static", ServeDir::new(path));
+            } else {
+                warn!(
+                    "Static assets path '{}' does not exist, skipping /static",
+                    path.display()
+                );
+            }
+        }
 
-        // Then add the state properly
-        router.with_state(self.state.clone())
+        // Then add the state properly and wrap every route in the protective layers
+        router
+            .with_state(self.state.clone())
+            .layer(RequestBodyLimitLayer::new(self.config.max_body_bytes))
+            .layer(TimeoutLayer::with_status_code(
+                StatusCode::REQUEST_TIMEOUT,
+                Duration::from_secs(self.config.request_timeout_seconds),
+            ))
     }
 
     // In your prism.rs file, update the serve method
@@ -165,27 +232,82 @@ impl PrismApi {
         let app = self.build_router();
 
         // Print welcome message before binding
-        self.print_welcome(&self.config.host, self.config.port);
-
-        // Use a string format to create a socket address
-        let socket_addr = format!("{}:{}", self.config.host, self.config.port)
-            .parse::<SocketAddr>()
-            .unwrap_or_else(|_| {
-                println!(
-                    "Warning: Could not parse host '{}', falling back to localhost",
-                    self.config.host
-                );
-                SocketAddr::from(([127, 0, 0, 1], self.config.port))
-            });
+        self.print_welcome(&self.config.bind);
+
+        match &self.config.bind {
+            BindAddress::Tcp(host, port) => {
+                // Resolve the host so both hostnames (e.g. "localhost") and IPs are supported
+                let socket_addr = tokio::net::lookup_host((host.as_str(), *port))
+                    .await?
+                    .next()
+                    .ok_or_else(|| format!("Could not resolve host '{}'", host))?;
 
-        println!("Binding to {}", socket_addr);
+                println!("Binding to {}", socket_addr);
 
-        // Create the listener with the resolved socket address
-        let listener = TcpListener::bind(socket_addr).await?;
+                // Create the listener with the resolved socket address
+                let listener = TcpListener::bind(socket_addr).await?;
 
-        // Serve the application
-        axum::serve(listener, app).await?;
+                // Serve the application
+                axum::serve(listener, app).await?;
+            }
+            BindAddress::Unix(path) => serve_unix(path, app).await?,
+        }
 
         Ok(())
     }
 }
+
+/// Serves `app` on a Unix socket until Ctrl+C or SIGTERM, then removes the socket file.
+/// A socket file left behind by an earlier run is replaced; any other file is not touched.
+#[cfg(unix)]
+async fn serve_unix(path: &Path, app: Router) -> std::io::Result<()> {
+    use std::os::unix::fs::FileTypeExt;
+    use tokio::{
+        net::UnixListener,
+        signal::unix::{SignalKind, signal},
+    };
+
+    if std::fs::symlink_metadata(path).is_ok_and(|m| m.file_type().is_socket()) {
+        std::fs::remove_file(path)?;
+    }
+    let listener = UnixListener::bind(path)?;
+    println!("Binding to {}", path.display());
+
+    let shutdown = async {
+        // If a handler cannot be installed, that signal simply never fires
+        let ctrl_c = async {
+            if tokio::signal::ctrl_c().await.is_err() {
+                std::future::pending::<()>().await;
+            }
+        };
+        let terminate = async {
+            match signal(SignalKind::terminate()) {
+                Ok(mut sigterm) => {
+                    sigterm.recv().await;
+                }
+                Err(_) => std::future::pending::<()>().await,
+            }
+        };
+        tokio::select! {
+            _ = ctrl_c => {},
+            _ = terminate => {},
+        }
+    };
+    let served = axum::serve(listener, app)
+        .with_graceful_shutdown(shutdown)
+        .await;
+
+    let removed = std::fs::remove_file(path);
+    served.and(removed)
+}
+
+#[cfg(not(unix))]
+async fn serve_unix(path: &Path, _app: Router) -> std::io::Result<()> {
+    Err(std::io::Error::new(
+        std::io::ErrorKind::Unsupported,
+        format!(
+            "Cannot listen on '{}': Unix sockets are not supported on this platform",
+            path.display()
+        ),
+    ))
+}